@@ -0,0 +1,313 @@
+//! Frame by frame playback of the simulation days, in the terminal or exported to a file.
+
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, BufRead, Write},
+    path::Path,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::error::ParseError;
+
+/// A simulation that can be drawn between its steps.
+pub trait Render {
+    /// Draws the current state, one line per row.
+    fn render(&self) -> String;
+
+    /// Advances by one frame, returning `false` once the simulation is over.
+    fn step(&mut self) -> bool;
+}
+
+/// Parses a puzzle input into the animation of a day.
+pub type Animation = fn(&str) -> Result<Box<dyn Render>, ParseError>;
+
+/// Every frame of `animation` from its current state, at most `limit` of them.
+pub fn frames(
+    mut animation: Box<dyn Render>,
+    limit: Option<usize>,
+) -> impl Iterator<Item = String> {
+    let mut started = false;
+    std::iter::from_fn(move || {
+        if started && !animation.step() {
+            return None;
+        }
+        started = true;
+        Some(animation.render())
+    })
+    .take(limit.unwrap_or(usize::MAX))
+}
+
+/// Commands of the interactive [`Player`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    TogglePause,
+    /// Shows the next frame, pausing the playback.
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// Reads [`Control`]s from the standard input, one per line: an empty line or `p` pauses or
+/// resumes, `s` steps, `+` and `-` change the speed and `q` quits.
+pub fn stdin_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            let control = match line.trim() {
+                "" | "p" => Control::TogglePause,
+                "s" | "n" => Control::Step,
+                "+" => Control::Faster,
+                "-" => Control::Slower,
+                "q" => Control::Quit,
+                _ => continue,
+            };
+            if sender.send(control).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// Slowest speed of the [`Player`], [`Control::Slower`] goes no further.
+pub const MIN_FPS: f64 = 0.125;
+
+/// Plays an animation in a terminal, redrawing the whole screen on every frame.
+pub struct Player {
+    fps: f64,
+    paused: bool,
+    limit: Option<usize>,
+}
+impl Player {
+    /// Plays at `fps` frames per second, or [`MIN_FPS`] if that is slower.
+    pub fn new(fps: f64) -> Self {
+        Self {
+            fps: fps.max(MIN_FPS),
+            paused: false,
+            limit: None,
+        }
+    }
+
+    /// Stops after `limit` frames.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Plays `animation` until it is over or [`Control::Quit`] is received, returning the number
+    /// of frames shown.
+    pub fn play(
+        &mut self,
+        mut animation: Box<dyn Render>,
+        out: &mut impl Write,
+        controls: &Receiver<Control>,
+    ) -> io::Result<usize> {
+        let mut shown = 1;
+        let mut over = false;
+        loop {
+            write!(out, "{CLEAR_SCREEN}{}\n\n", animation.render())?;
+            let state = match (over, self.paused) {
+                (true, _) => "over",
+                (false, true) => "paused",
+                (false, false) => "playing",
+            };
+            writeln!(
+                out,
+                "frame {shown}, {:.1} fps, {state} (enter: pause, s: step, +/-: speed, q: quit)",
+                self.fps
+            )?;
+            out.flush()?;
+            if over {
+                return Ok(shown);
+            }
+            loop {
+                let control = if self.paused {
+                    controls.recv().map_err(|_| RecvTimeoutError::Disconnected)
+                } else {
+                    controls.recv_timeout(Duration::from_secs_f64(1.0 / self.fps))
+                };
+                match control {
+                    Ok(Control::TogglePause) => self.paused = !self.paused,
+                    Ok(Control::Step) => {
+                        self.paused = true;
+                        break;
+                    }
+                    Ok(Control::Faster) => self.fps *= 2.0,
+                    Ok(Control::Slower) => self.fps = (self.fps / 2.0).max(MIN_FPS),
+                    Ok(Control::Quit) => return Ok(shown),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    // nobody can unpause anymore, play to the end
+                    Err(RecvTimeoutError::Disconnected) if self.paused => self.paused = false,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            over = self.limit == Some(shown) || !animation.step();
+            if !over {
+                shown += 1;
+            }
+        }
+    }
+}
+
+/// Writes `frames` as an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/)
+/// recording played at `fps`, returning the number of frames written.
+///
+/// Panics unless `fps` is positive, as the frames would have no time.
+pub fn write_asciicast(
+    frames: impl Iterator<Item = String>,
+    fps: f64,
+    out: &mut impl Write,
+) -> io::Result<usize> {
+    assert!(fps > 0.0 && fps.is_finite(), "{fps} frames per second");
+    let frames = frames.collect::<Vec<_>>();
+    let width = frames
+        .iter()
+        .flat_map(|frame| frame.lines())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let height = frames
+        .iter()
+        .map(|frame| frame.lines().count())
+        .max()
+        .unwrap_or(0);
+    writeln!(
+        out,
+        r#"{{"version": 2, "width": {width}, "height": {height}}}"#
+    )?;
+    for (i, frame) in frames.iter().enumerate() {
+        let data = format!("{CLEAR_SCREEN}{}", frame.replace('\n', "\r\n"));
+        writeln!(
+            out,
+            "[{:.3}, \"o\", \"{}\"]",
+            i as f64 / fps,
+            json_escape(&data)
+        )?;
+    }
+    Ok(frames.len())
+}
+
+/// Writes every frame to its own `frame-NNNNN.txt` file in `dir`, returning how many there were.
+pub fn write_frame_dump(frames: impl Iterator<Item = String>, dir: &Path) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+    let mut written = 0;
+    for (i, frame) in frames.enumerate() {
+        fs::write(dir.join(format!("frame-{i:05}.txt")), frame + "\n")?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            ch if ch.is_control() => write!(escaped, "\\u{:04x}", ch as u32).unwrap(),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    /// Counts from 0 to `end`, drawing a bar as long as the count.
+    struct Counter {
+        count: usize,
+        end: usize,
+    }
+    impl Render for Counter {
+        fn render(&self) -> String {
+            format!("{}\n{}", self.count, "#".repeat(self.count))
+        }
+
+        fn step(&mut self) -> bool {
+            self.count += 1;
+            self.count <= self.end
+        }
+    }
+
+    fn counter(end: usize) -> Box<dyn Render> {
+        Box::new(Counter { count: 0, end })
+    }
+
+    #[test]
+    fn frames_test() {
+        assert_eq!(
+            frames(counter(2), None).collect::<Vec<_>>(),
+            ["0\n", "1\n#", "2\n##"]
+        );
+        assert_eq!(frames(counter(5), Some(2)).count(), 2);
+    }
+
+    #[test]
+    fn asciicast_test() {
+        let mut out = Vec::new();
+        assert_eq!(
+            write_asciicast(frames(counter(2), None), 2.0, &mut out).unwrap(),
+            3
+        );
+        let lines = String::from_utf8(out).unwrap();
+        let lines = lines.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], r#"{"version": 2, "width": 2, "height": 2}"#);
+        assert_eq!(lines[2], r#"[0.500, "o", "\u001b[H\u001b[2J1\r\n#"]"#);
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn frame_dump_test() {
+        let dir = env::temp_dir().join(format!("aoc-frames-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(write_frame_dump(frames(counter(3), None), &dir).unwrap(), 4);
+        assert_eq!(
+            fs::read_to_string(dir.join("frame-00003.txt")).unwrap(),
+            "3\n###\n"
+        );
+    }
+
+    #[test]
+    fn player_test() {
+        let (sender, receiver) = mpsc::channel();
+        let mut out = Vec::new();
+        // played to the end
+        assert_eq!(
+            Player::new(1000.0)
+                .play(counter(3), &mut out, &receiver)
+                .unwrap(),
+            4
+        );
+
+        sender.send(Control::Step).unwrap();
+        sender.send(Control::Step).unwrap();
+        sender.send(Control::Quit).unwrap();
+        let mut player = Player::new(1000.0);
+        assert_eq!(player.play(counter(10), &mut out, &receiver).unwrap(), 3);
+        assert!(player.paused);
+
+        let mut player = Player::new(1000.0).with_limit(5);
+        assert_eq!(player.play(counter(10), &mut out, &receiver).unwrap(), 5);
+
+        for _ in 0..20 {
+            sender.send(Control::Slower).unwrap();
+        }
+        sender.send(Control::Quit).unwrap();
+        let mut player = Player::new(1.0);
+        assert_eq!(player.play(counter(10), &mut out, &receiver).unwrap(), 1);
+        assert_eq!(player.fps, MIN_FPS);
+        assert_eq!(Player::new(0.0).fps, MIN_FPS);
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Malformed puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Day whose parser failed, `0` until [`ParseError::with_day`] fills it in.
+    pub day: u8,
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, in characters, of the offending text.
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, reason: impl Into<String>) -> Self {
+        Self {
+            day: 0,
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        Self { day, ..self }
+    }
+
+    /// Renders the error together with the offending line of `input`, compiler style.
+    pub fn report(&self, filename: &str, input: &str) -> String {
+        let mut report = format!(
+            "error: {self}\n --> {filename}:{}:{}",
+            self.line, self.column
+        );
+        if let Some(line) = input.lines().nth(self.line.wrapping_sub(1)) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let underline = "^".repeat(self.text.chars().count().max(1));
+            let indent = " ".repeat(self.column - 1);
+            report.push_str(&format!(
+                "\n{gutter} |\n{} | {line}\n{gutter} | {indent}{underline}",
+                self.line
+            ));
+        }
+        report
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.day > 0 {
+            write!(f, "day {:02}, ", self.day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text.escape_debug())?;
+        }
+        Ok(())
+    }
+}
+impl Error for ParseError {}
+
+/// Puzzle input being parsed, used to turn slices of it into [`ParseError`]s with positions.
+///
+/// Parsers keep working on `&str` slices of the original input (`lines`, `split_once`,
+/// `trim`...), so the position of any slice can be recovered from its address.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    /// Error pointing at `text`, which should be a slice of the input.
+    pub fn error(&self, text: &str, reason: impl Into<String>) -> ParseError {
+        let (line, column) = self.position(text);
+        ParseError::new(line, column, text, reason)
+    }
+
+    /// Error pointing right after the last character of the input.
+    pub fn eof(&self, reason: impl Into<String>) -> ParseError {
+        self.error(&self.input[self.input.len()..], reason)
+    }
+
+    pub fn number<T>(&self, text: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.parse()
+            .map_err(|err| self.error(text, format!("invalid number: {err}")))
+    }
+
+    pub fn split_once<'b>(
+        &self,
+        text: &'b str,
+        delimiter: &str,
+    ) -> Result<(&'b str, &'b str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("expected `{}`", delimiter.escape_debug())))
+    }
+
+    pub fn strip_prefix<'b>(&self, text: &'b str, prefix: &str) -> Result<&'b str, ParseError> {
+        text.strip_prefix(prefix)
+            .ok_or_else(|| self.error(text, format!("expected `{prefix}`")))
+    }
+
+    pub fn strip_suffix<'b>(&self, text: &'b str, suffix: &str) -> Result<&'b str, ParseError> {
+        text.strip_suffix(suffix)
+            .ok_or_else(|| self.error(text, format!("expected `{suffix}` at the end")))
+    }
+
+    fn position(&self, text: &str) -> (usize, usize) {
+        let start = self.input.as_ptr() as usize;
+        let offset = match (text.as_ptr() as usize).checked_sub(start) {
+            Some(offset) if offset <= self.input.len() => offset,
+            // not a slice of the input, fall back to its first occurrence
+            _ => self.input.find(text).unwrap_or(self.input.len()),
+        };
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_test() {
+        let input = "12\n34 x6\n";
+        let source = Source::new(input);
+        let line = input.lines().nth(1).unwrap();
+        let err = source.number::<u8>(&line[3..5]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "x6"));
+        assert_eq!(source.eof("truncated").line, 3);
+    }
+
+    #[test]
+    fn helpers_test() {
+        let source = Source::new("move 1 from 2");
+        assert_eq!(source.strip_prefix("move 1", "move "), Ok("1"));
+        assert_eq!(source.split_once("1 from 2", " from "), Ok(("1", "2")));
+        let err = source.strip_suffix("from 2", " to").unwrap_err();
+        assert_eq!(err.reason, "expected ` to` at the end");
+    }
+
+    #[test]
+    fn report_test() {
+        let input = "abc\nde?f";
+        let err = Source::new(input).error(&input[6..7], "unexpected character");
+        assert_eq!(
+            err.with_day(7).report("input.txt", input),
+            "error: day 07, line 2, column 3: unexpected character (found `?`)\n \
+             --> input.txt:2:3\n  |\n2 | de?f\n  |   ^"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Part, Solution};
+
+/// Embeds the fixture `$name.txt` from the root of the calling crate.
+///
+/// The path is resolved at compile time against `CARGO_MANIFEST_DIR`, so tests pass whatever
+/// directory they are run from.
+#[macro_export]
+macro_rules! fixture {
+    ($name:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $name, ".txt"))
+    };
+}
+
+/// Worked example from the puzzle description, with the answers it is known to produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+impl Example {
+    pub const fn new(name: &'static str, input: &'static str) -> Self {
+        Self {
+            name,
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub const fn part1(self, answer: &'static str) -> Self {
+        Self {
+            part1: Some(answer),
+            ..self
+        }
+    }
+
+    pub const fn part2(self, answer: &'static str) -> Self {
+        Self {
+            part2: Some(answer),
+            ..self
+        }
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+/// Solves every [`Solution::EXAMPLES`] of `S`, panicking on the first unexpected answer.
+pub fn assert_examples<S: Solution>() {
+    for example in S::EXAMPLES {
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|&part| example.expected(part).is_some())
+            .collect::<Vec<_>>();
+        let report = S::run(example.input, &parts)
+            .unwrap_or_else(|err| panic!("example {}: {err}", example.name));
+        for part in report.parts {
+            assert_eq!(
+                part.answer,
+                example.expected(part.part).unwrap(),
+                "example {} {}",
+                example.name,
+                part.part
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+    use std::fmt::Display;
+
+    struct Lines;
+    impl Solution for Lines {
+        const DAY: u8 = 0;
+        const EXAMPLES: &'static [Example] = &[
+            Example::new("three", "a\nb\nc").part1("3").part2("c"),
+            Example::new("one", "x").part2("x"),
+        ];
+
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(lines: &Self::Input) -> impl Display {
+            lines.len()
+        }
+
+        fn part2(lines: &Self::Input) -> impl Display {
+            lines.last().cloned().unwrap_or_default()
+        }
+    }
+
+    #[test]
+    fn assert_examples_test() {
+        assert_examples::<Lines>();
+    }
+
+    #[test]
+    #[should_panic(expected = "example three part1")]
+    fn assert_examples_failure_test() {
+        struct Wrong;
+        impl Solution for Wrong {
+            const DAY: u8 = 0;
+            const EXAMPLES: &'static [Example] = &[Example::new("three", "a\nb\nc").part1("4")];
+
+            type Input = Vec<String>;
+
+            fn parse(input: &str) -> Result<Self::Input, ParseError> {
+                Lines::parse(input)
+            }
+
+            fn part1(lines: &Self::Input) -> impl Display {
+                Lines::part1(lines)
+            }
+
+            fn part2(lines: &Self::Input) -> impl Display {
+                Lines::part2(lines)
+            }
+        }
+        assert_examples::<Wrong>();
+    }
+}
@@ -0,0 +1,38 @@
+//! Seeded random puzzle inputs, to stress the solutions with inputs of any size.
+//!
+//! Every day has a `gen` module whose `generate` writes a valid input in the exact format of the
+//! puzzle, `size` scaling whatever makes that day expensive. Its `MIN_SIZE` is the smallest size
+//! it generates, smaller ones are raised to it.
+
+use crate::Solution;
+
+pub use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Writes a random input of the given `size`, whose meaning depends on the day.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+/// Random generator producing the same inputs for the same `seed`.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Checks for a few seeds that `generate` only depends on the seed and that `S` solves what it
+/// generates.
+pub fn assert_generates<S: Solution>(generate: Generator, size: usize) {
+    for seed in 0..3 {
+        let input = generate(&mut rng(seed), size);
+        assert_eq!(
+            input,
+            generate(&mut rng(seed), size),
+            "day {} seed {seed}",
+            S::DAY
+        );
+        if let Err(err) = S::solve(&input) {
+            panic!(
+                "day {} seed {seed}: {}\n{input}",
+                S::DAY,
+                err.report("generated", &input)
+            );
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point on a plane, with `y` growing downwards as in the puzzle maps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+impl Point2 {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// `(y, x)` position, as expected by [`crate::Grid::get_signed`].
+    pub fn yx(self) -> (isize, isize) {
+        (self.y, self.x)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Number of king moves between the two points.
+    pub fn chebyshev_distance(self, other: Self) -> isize {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Sign of every coordinate, i.e. a single step towards the direction of `self`.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates a quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn counter-clockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Orthogonal neighbours, clockwise starting from up.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction4::ALL
+            .into_iter()
+            .map(move |dir| self + dir.offset())
+    }
+
+    /// Orthogonal and diagonal neighbours, clockwise starting from up.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |dir| self + dir.offset())
+    }
+}
+impl From<(isize, isize)> for Point2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// Point in space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    /// Offsets to the six neighbours sharing a face.
+    pub const FACES: [Self; 6] = [
+        Self::new(1, 0, 0),
+        Self::new(-1, 0, 0),
+        Self::new(0, 1, 0),
+        Self::new(0, -1, 0),
+        Self::new(0, 0, 1),
+        Self::new(0, 0, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> isize {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// Neighbours sharing a face, see [`Point3::FACES`].
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        Self::FACES.into_iter().map(move |diff| self + diff)
+    }
+}
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+        impl Mul<isize> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: isize) -> Self {
+                Self { $($field: self.$field * factor),* }
+            }
+        }
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+/// Orthogonal direction on a map, `Up` being towards decreasing `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+impl Direction4 {
+    /// Every direction, clockwise starting from up.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Self::Up => Point2::new(0, -1),
+            Self::Right => Point2::new(1, 0),
+            Self::Down => Point2::new(0, 1),
+            Self::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Orthogonal or diagonal direction on a map, `North` being towards decreasing `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+impl Direction8 {
+    /// Every direction, clockwise starting from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Self::North => Point2::new(0, -1),
+            Self::NorthEast => Point2::new(1, -1),
+            Self::East => Point2::new(1, 0),
+            Self::SouthEast => Point2::new(1, 1),
+            Self::South => Point2::new(0, 1),
+            Self::SouthWest => Point2::new(-1, 1),
+            Self::West => Point2::new(-1, 0),
+            Self::NorthWest => Point2::new(-1, -1),
+        }
+    }
+
+    /// Turns an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns an eighth of a turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        match dir {
+            Direction4::Up => Self::North,
+            Direction4::Right => Self::East,
+            Direction4::Down => Self::South,
+            Direction4::Left => Self::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_test() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+        assert_eq!(
+            Point3::new(1, 2, 3).manhattan_distance(Point3::new(0, 4, -1)),
+            7
+        );
+        assert_eq!(Point3::ORIGIN.neighbours6().count(), 6);
+    }
+
+    #[test]
+    fn rotation_test() {
+        let up = Direction4::Up.offset();
+        assert_eq!(up.rotate_right(), Direction4::Right.offset());
+        assert_eq!(up.rotate_left(), Direction4::Left.offset());
+        for dir in Direction4::ALL {
+            assert_eq!(dir.offset().rotate_right(), dir.turn_right().offset());
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().offset(), -dir.offset());
+        }
+        for dir in Direction8::ALL {
+            assert_eq!(dir.opposite().offset(), -dir.offset());
+            assert_eq!(
+                dir.turn_right().turn_right().offset(),
+                dir.offset().rotate_right()
+            );
+        }
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let p = Point2::new(3, 3);
+        assert_eq!(
+            p.neighbours4().collect::<Vec<_>>(),
+            [(3, 2), (4, 3), (3, 4), (2, 3)].map(Point2::from)
+        );
+        assert!(p.neighbours8().all(|n| n.chebyshev_distance(p) == 1));
+        assert_eq!(p.neighbours8().count(), 8);
+    }
+}
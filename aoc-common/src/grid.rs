@@ -0,0 +1,398 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::error::{ParseError, Source};
+
+/// Grid position as `(y, x)`, i.e. `(row, column)`, matching `map[y][x]` indexing.
+pub type Pos = (usize, usize);
+
+/// `(y, x)` offsets of the orthogonal neighbours, clockwise starting from up.
+pub const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// `(y, x)` offsets of the orthogonal and diagonal neighbours, clockwise starting from up-left.
+pub const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Dense, row-major 2D grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from row-major `cells`; `cells.len()` must be a multiple of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width == 0 && cells.is_empty() || width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fit into rows of width {width}",
+            cells.len()
+        );
+        let height = cells.len().checked_div(width).unwrap_or_default();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, mapping every character with `f`, which returns `None` for
+    /// characters that are not allowed.
+    ///
+    /// Lines must all be of the same length, use [`Grid::parse_padded`] for ragged input.
+    pub fn parse<F>(text: &str, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let source = Source::new(text);
+        let mut width = None;
+        let mut cells = Vec::new();
+        for line in text.lines() {
+            let before = cells.len();
+            parse_row(&source, line, &mut f, &mut cells)?;
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(source.error(line, format!("expected a row of {w} cells")));
+                }
+                _ => {}
+            }
+        }
+        Ok(Self::from_vec(width.unwrap_or_default(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns whether a possibly negative `(y, x)` lies inside the grid.
+    pub fn contains(&self, (y, x): (isize, isize)) -> bool {
+        y >= 0 && x >= 0 && (y as usize) < self.height && (x as usize) < self.width
+    }
+
+    pub fn get(&self, (y, x): Pos) -> Option<&T> {
+        if y < self.height && x < self.width {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (y, x): Pos) -> Option<&mut T> {
+        if y < self.height && x < self.width {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Grid::get`], but for coordinates that may have stepped outside the grid.
+    pub fn get_signed(&self, pos: (isize, isize)) -> Option<&T> {
+        if self.contains(pos) {
+            self.get((pos.0 as usize, pos.1 as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Moves `pos` by `diff`, returning `None` when the result falls outside the grid.
+    pub fn checked_offset(&self, (y, x): Pos, (y_diff, x_diff): (isize, isize)) -> Option<Pos> {
+        let next = (y as isize + y_diff, x as isize + x_diff);
+        if self.contains(next) {
+            Some((next.0 as usize, next.1 as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Moves `pos` by `diff`, wrapping around the edges as if the grid were a torus.
+    pub fn wrapping_offset(&self, (y, x): Pos, (y_diff, x_diff): (isize, isize)) -> Pos {
+        (
+            (y as isize + y_diff).rem_euclid(self.height as isize) as usize,
+            (x as isize + x_diff).rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    /// Orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS4
+            .into_iter()
+            .filter_map(move |diff| self.checked_offset(pos, diff))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS8
+            .into_iter()
+            .filter_map(move |diff| self.checked_offset(pos, diff))
+    }
+
+    /// Walks from `pos` (exclusive) in steps of `diff` until the edge of the grid.
+    pub fn ray(&self, pos: Pos, diff: (isize, isize)) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        std::iter::successors(self.checked_offset(pos, diff), move |&curr| {
+            self.checked_offset(curr, diff)
+        })
+        .map(|curr| (curr, &self[curr]))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.height).map(move |y| &self[(y, x)])
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (y, x)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// First position, in row-major order, whose cell satisfies `predicate`.
+    pub fn position<P>(&self, mut predicate: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let idx = self.cells.iter().position(&mut predicate)?;
+        Some((idx / self.width, idx % self.width))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Appends a row at the bottom; the first row pushed into an empty grid sets its width.
+    pub fn push_row<I>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let before = self.cells.len();
+        self.cells.extend(row);
+        let row_width = self.cells.len() - before;
+        if self.height == 0 {
+            self.width = row_width;
+        }
+        assert_eq!(row_width, self.width, "row width mismatch");
+        self.height += 1;
+    }
+
+    /// Removes the bottom row.
+    pub fn pop_row(&mut self) -> Option<Vec<T>> {
+        if self.height == 0 {
+            return None;
+        }
+        self.height -= 1;
+        Some(self.cells.split_off(self.height * self.width))
+    }
+
+    /// Removes the first `n` rows, shifting the remaining ones up.
+    pub fn remove_rows_front(&mut self, n: usize) {
+        let n = n.min(self.height);
+        self.cells.drain(..n * self.width);
+        self.height -= n;
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per line, padding lines shorter than the longest one with `fill`.
+    pub fn parse_padded<F>(text: &str, fill: T, mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let source = Source::new(text);
+        let width = text
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+        let mut grid = Self {
+            width,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for line in text.lines() {
+            let row_start = grid.cells.len();
+            parse_row(&source, line, &mut f, &mut grid.cells)?;
+            grid.cells.resize(row_start + width, fill.clone());
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+}
+
+fn parse_row<T, F>(
+    source: &Source,
+    line: &str,
+    f: &mut F,
+    cells: &mut Vec<T>,
+) -> Result<(), ParseError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    for (idx, ch) in line.char_indices() {
+        let cell = f(ch)
+            .ok_or_else(|| source.error(&line[idx..idx + ch.len_utf8()], "unexpected character"))?;
+        cells.push(cell);
+    }
+    Ok(())
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (y, x): Pos) -> &Self::Output {
+        assert!(
+            y < self.height && x < self.width,
+            "({y}, {x}) is out of bounds for a {}x{} grid",
+            self.height,
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (y, x): Pos) -> &mut Self::Output {
+        assert!(
+            y < self.height && x < self.width,
+            "({y}, {x}) is out of bounds for a {}x{} grid",
+            self.height,
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display_test() {
+        let text = "#..\n.#.\n..#";
+        let grid = Grid::parse(text, Some).unwrap();
+        assert_eq!((grid.height(), grid.width()), (3, 3));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid.to_string(), text);
+    }
+
+    #[test]
+    fn parse_padded_test() {
+        let grid = Grid::parse_padded("  #\n#", ' ', Some).unwrap();
+        assert_eq!(grid.to_string(), "  #\n#  ");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = Grid::parse("12\n3x", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        let err = Grid::parse("12\n345", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (2, "expected a row of 2 cells")
+        );
+    }
+
+    #[test]
+    fn offsets_test() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.checked_offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.checked_offset((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.wrapping_offset((0, 0), (-1, -1)), (1, 2));
+        assert_eq!(grid.wrapping_offset((1, 2), (1, 1)), (0, 0));
+        assert!(!grid.contains((2, 0)));
+        assert_eq!(grid.get_signed((-1, 0)), None);
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn iterators_test() {
+        let grid = Grid::parse("123\n456\n789", |ch| ch.to_digit(10)).unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            [((1, 1), &5), ((2, 2), &9)]
+        );
+        assert_eq!(grid.position(|&v| v == 6), Some((1, 2)));
+    }
+
+    #[test]
+    fn rows_push_pop_test() {
+        let mut grid = Grid::from_vec(2, vec![1, 2]);
+        grid.push_row([3, 4]);
+        grid.push_row([5, 6]);
+        assert_eq!(grid.pop_row(), Some(vec![5, 6]));
+        grid.remove_rows_front(1);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[3, 4]]);
+    }
+}
@@ -0,0 +1,196 @@
+use std::ops::RangeInclusive;
+
+/// Set of integers stored as sorted, disjoint, inclusive ranges.
+///
+/// Overlapping and adjacent ranges are merged as they are inserted, so every operation works
+/// on the smallest possible number of ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<(i64, i64)>,
+}
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|&(_, to)| to.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(from, _)| from <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Integers in `self` or in `other`.
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        for range in other.ranges() {
+            merged.insert(range);
+        }
+        merged
+    }
+
+    /// Integers in both `self` and `other`.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(a_from, a_to)), Some(&&(b_from, b_to))) = (a.peek(), b.peek()) {
+            let (from, to) = (a_from.max(b_from), a_to.min(b_to));
+            if from <= to {
+                ranges.push((from, to));
+            }
+            if a_to < b_to {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Integers in `self` but not in `other`.
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.intersect(&other.complement(i64::MIN..=i64::MAX))
+    }
+
+    /// Integers of `bounds` that are not in `self`.
+    pub fn complement(&self, bounds: RangeInclusive<i64>) -> Self {
+        let (start, end) = bounds.into_inner();
+        let mut ranges = Vec::new();
+        // `None` once the end of `bounds` is covered
+        let mut cursor = Some(start);
+        for &(from, to) in &self.ranges {
+            let Some(next) = cursor.filter(|&next| next <= end) else {
+                break;
+            };
+            if from > next {
+                ranges.push((next, (from - 1).min(end)));
+            }
+            if to >= next {
+                cursor = to.checked_add(1);
+            }
+        }
+        if let Some(next) = cursor.filter(|&next| next <= end) {
+            ranges.push((next, end));
+        }
+        Self { ranges }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|&(_, to)| to < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|&(from, _)| from <= value)
+    }
+
+    /// Number of integers in the set, saturating at `u64::MAX`: the set of every `i64` has one
+    /// more.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|&(from, to)| to.abs_diff(from).saturating_add(1))
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Disjoint ranges of the set, in increasing order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<i64>> + '_ {
+        self.ranges.iter().map(|&(from, to)| from..=to)
+    }
+
+    /// Ranges missing between the lowest and the highest integer of the set, in increasing order.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<i64>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|pair| pair[0].1 + 1..=pair[1].0 - 1)
+    }
+}
+impl FromIterator<RangeInclusive<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+impl From<RangeInclusive<i64>> for IntervalSet {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_test() {
+        let mut ranges = set(&[10..=12, 0..=2, 5..=6]);
+        assert_eq!(ranges.ranges().collect::<Vec<_>>(), [0..=2, 5..=6, 10..=12]);
+        ranges.insert(3..=4);
+        assert_eq!(ranges.ranges().collect::<Vec<_>>(), [0..=6, 10..=12]);
+        ranges.insert(8..=20);
+        let (start, end) = (-5, -10);
+        ranges.insert(start..=end);
+        assert_eq!(ranges.ranges().collect::<Vec<_>>(), [0..=6, 8..=20]);
+        ranges.insert(-1..=30);
+        assert_eq!(ranges.ranges().collect::<Vec<_>>(), [-1..=30]);
+        assert_eq!(ranges.len(), 32);
+    }
+
+    #[test]
+    fn set_operations_test() {
+        let a = set(&[0..=5, 10..=15]);
+        let b = set(&[3..=12, 20..=20]);
+        assert_eq!(a.merge(&b), set(&[0..=15, 20..=20]));
+        assert_eq!(a.intersect(&b), set(&[3..=5, 10..=12]));
+        assert_eq!(a.subtract(&b), set(&[0..=2, 13..=15]));
+        assert_eq!(b.subtract(&a), set(&[6..=9, 20..=20]));
+        assert!(a.intersect(&set(&[6..=9])).is_empty());
+    }
+
+    #[test]
+    fn complement_test() {
+        let a = set(&[0..=5, 10..=15]);
+        assert_eq!(a.complement(-3..=20), set(&[-3..=-1, 6..=9, 16..=20]));
+        assert_eq!(a.complement(2..=12), set(&[6..=9]));
+        assert_eq!(a.complement(1..=4), IntervalSet::new());
+        assert_eq!(
+            set(&[i64::MIN..=0, 5..=i64::MAX]).complement(i64::MIN..=i64::MAX),
+            set(&[1..=4])
+        );
+    }
+
+    #[test]
+    fn query_test() {
+        let a = set(&[0..=5, 10..=15, 17..=17]);
+        assert!(a.contains(0) && a.contains(12) && a.contains(17));
+        assert!(!a.contains(-1) && !a.contains(7) && !a.contains(16));
+        assert_eq!(a.len(), 13);
+        assert_eq!(a.gaps().collect::<Vec<_>>(), [6..=9, 16..=16]);
+        assert_eq!(set(&[i64::MIN..=-2, 1..=i64::MAX]).len(), u64::MAX - 1);
+        assert_eq!(set(&[i64::MIN..=i64::MAX]).len(), u64::MAX);
+    }
+}
@@ -0,0 +1,17 @@
+pub mod animation;
+pub mod error;
+pub mod example;
+pub mod gen;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod logging;
+pub mod search;
+pub mod solution;
+
+pub use error::{ParseError, Source};
+pub use example::Example;
+pub use geometry::{Direction4, Direction8, Point2, Point3};
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use solution::{MaybeAnswer, Part, Solution};
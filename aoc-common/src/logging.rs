@@ -0,0 +1,33 @@
+//! Diagnostics of the solutions, silent unless asked for.
+//!
+//! Days log with [`tracing`] under their crate name, e.g. `day_19`, and [`Solution::run`] opens a
+//! span for parsing and for each part, whose timings are logged when it closes.
+//!
+//! [`Solution::run`]: crate::Solution::run
+
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+/// Environment variable selecting what to log, e.g. `day_19=trace`, see [`EnvFilter`].
+pub const FILTER_ENV: &str = "AOC_LOG";
+
+/// Logs to the standard error what [`FILTER_ENV`] selects or, failing that, everything down to
+/// the debug level when `verbose`.
+///
+/// Without either nothing is installed, so logging costs next to nothing.
+pub fn init(verbose: bool) {
+    let filter = match env::var(FILTER_ENV) {
+        Ok(filter) => EnvFilter::new(filter),
+        Err(_) if verbose => EnvFilter::new("debug"),
+        Err(_) => return,
+    };
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(io::stderr().is_terminal())
+        .with_writer(io::stderr)
+        .try_init();
+}
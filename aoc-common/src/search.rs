@@ -0,0 +1,250 @@
+//! Shortest path searches over graphs given as a `neighbours` closure.
+//!
+//! Every search accepts several starting nodes, all at cost 0, and stops at the first node for
+//! which `is_goal` holds.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Cheapest way found from a start to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    /// Every node on the way, from the start to the goal inclusive.
+    pub nodes: Vec<N>,
+}
+
+/// Nodes seen so far, each with the cheapest cost found yet and the node it was reached from.
+struct Visited<N> {
+    nodes: Vec<(N, usize, Option<usize>)>,
+    index: HashMap<N, usize>,
+}
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Records that `node` can be reached for `cost` from `parent`, returning its index if that
+    /// is cheaper than any way found before.
+    fn relax(&mut self, node: N, cost: usize, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Occupied(entry) => {
+                let idx = *entry.get();
+                (cost < self.nodes[idx].1).then(|| {
+                    self.nodes[idx].1 = cost;
+                    self.nodes[idx].2 = parent;
+                    idx
+                })
+            }
+            Entry::Vacant(entry) => {
+                let idx = self.nodes.len();
+                self.nodes.push((entry.key().clone(), cost, parent));
+                entry.insert(idx);
+                Some(idx)
+            }
+        }
+    }
+
+    fn path(&self, mut idx: usize) -> Path<N> {
+        let cost = self.nodes[idx].1;
+        let mut nodes = vec![self.nodes[idx].0.clone()];
+        while let Some(parent) = self.nodes[idx].2 {
+            nodes.push(self.nodes[parent].0.clone());
+            idx = parent;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Breadth-first search, for graphs where every step costs 1.
+pub fn bfs<N, I, F, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: F,
+    mut is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut queue = starts
+        .into_iter()
+        .filter_map(|start| visited.relax(start, 0, None))
+        .collect::<VecDeque<_>>();
+    while let Some(idx) = queue.pop_front() {
+        let (node, cost, _) = visited.nodes[idx].clone();
+        if is_goal(&node) {
+            return Some(visited.path(idx));
+        }
+        for next in neighbours(&node) {
+            if !visited.index.contains_key(&next) {
+                queue.extend(visited.relax(next, cost + 1, Some(idx)));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm, for graphs whose `neighbours` come with the cost of the step to them.
+pub fn dijkstra<N, I, F, G>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: F,
+    is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to the closest goal.
+pub fn astar<N, I, F, H, G>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: FnMut(&N) -> usize,
+    G: FnMut(&N) -> bool,
+{
+    let (visited, goal) = explore(starts, neighbours, heuristic, is_goal);
+    goal.map(|idx| visited.path(idx))
+}
+
+/// Cost of the cheapest path from any of `starts` to every reachable node.
+pub fn distances<N, I, F>(starts: impl IntoIterator<Item = N>, neighbours: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let (visited, _) = explore(starts, neighbours, |_| 0, |_| false);
+    visited
+        .nodes
+        .into_iter()
+        .map(|(node, cost, _)| (node, cost))
+        .collect()
+}
+
+/// Best-first search shared by [`astar`] and [`distances`], returning the index of the goal.
+fn explore<N, I, F, H, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> (Visited<N>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: FnMut(&N) -> usize,
+    G: FnMut(&N) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(idx) = visited.relax(start, 0, None) {
+            heap.push(Reverse((estimate, 0, idx)));
+        }
+    }
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        if cost > visited.nodes[idx].1 {
+            continue;
+        }
+        let node = visited.nodes[idx].0.clone();
+        if is_goal(&node) {
+            return (visited, Some(idx));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next_idx) = visited.relax(next, next_cost, Some(idx)) {
+                heap.push(Reverse((estimate, next_cost, next_idx)));
+            }
+        }
+    }
+    (visited, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps of 1 along the line, or jumps of 5 for a cost of 3.
+    fn line(&n: &i32) -> Vec<(i32, usize)> {
+        vec![(n - 1, 1), (n + 1, 1), (n + 5, 3)]
+    }
+
+    #[test]
+    fn bfs_test() {
+        let path = bfs([0], |&n| [n + 1, n + 5], |&n| n == 12).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes.first(), Some(&0));
+        assert_eq!(path.nodes.last(), Some(&12));
+        assert_eq!(path.nodes.len(), 5);
+        assert_eq!(
+            bfs([0], |&n| [n + 2], |&n| n == 3 || n > 10)
+                .unwrap()
+                .nodes
+                .last(),
+            Some(&12)
+        );
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let path = dijkstra([0], line, |&n| n == 10).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes, [0, 5, 10]);
+        assert_eq!(
+            astar(
+                [0],
+                line,
+                |&n| (10 - n).unsigned_abs() as usize * 3 / 5,
+                |&n| n == 10
+            ),
+            Some(path)
+        );
+    }
+
+    #[test]
+    fn multi_source_test() {
+        let path = dijkstra([0, 9], line, |&n| n == 11).unwrap();
+        assert_eq!(path.nodes, [9, 10, 11]);
+        assert_eq!(
+            bfs([0], |&n| (n < 5).then_some(n + 1), |&n| n < 0),
+            None::<Path<i32>>
+        );
+    }
+
+    #[test]
+    fn distances_test() {
+        let dist = distances([0], |&n: &i32| {
+            line(&n)
+                .into_iter()
+                .filter(|&(next, _)| (0..=10).contains(&next))
+        });
+        assert_eq!(dist.len(), 11);
+        assert_eq!(dist[&0], 0);
+        assert_eq!(dist[&4], 4);
+        assert_eq!(dist[&6], 4);
+        assert_eq!(dist[&10], 6);
+    }
+}
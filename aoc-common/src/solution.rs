@@ -0,0 +1,197 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::read_to_string,
+    io::{self, Read},
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParseError, example::Example, logging};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            invalid => Err(format!("there is no part {invalid}")),
+        }
+    }
+}
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part{}", self.number())
+    }
+}
+
+/// A single day of the calendar: parses the puzzle input once and answers both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    /// Examples from the puzzle description, checked by [`crate::example::assert_examples`].
+    const EXAMPLES: &'static [Example] = &[];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+
+    /// Parses `input` and solves both parts, for callers that hold the input in memory.
+    fn solve(input: &str) -> Result<[String; 2], ParseError>
+    where
+        Self: Sized,
+    {
+        let report = Self::run(input, &Part::ALL)?;
+        let [part1, part2] = &report.parts[..] else {
+            unreachable!("both parts were run")
+        };
+        Ok([part1.answer.clone(), part2.answer.clone()])
+    }
+
+    /// Parses `input` and solves the requested `parts`, timing every phase separately.
+    fn run(input: &str, parts: &[Part]) -> Result<DayReport, ParseError>
+    where
+        Self: Sized,
+    {
+        let _day = tracing::info_span!("day", day = Self::DAY).entered();
+        let instant = Instant::now();
+        let parsed = tracing::info_span!("parse")
+            .in_scope(|| Self::parse(input))
+            .map_err(|err| err.with_day(Self::DAY))?;
+        let parse_duration = instant.elapsed();
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let _span = match part {
+                    Part::One => tracing::info_span!("part1"),
+                    Part::Two => tracing::info_span!("part2"),
+                }
+                .entered();
+                let instant = Instant::now();
+                let answer = match part {
+                    Part::One => Self::part1(&parsed).to_string(),
+                    Part::Two => Self::part2(&parsed).to_string(),
+                };
+                PartReport {
+                    part,
+                    answer,
+                    duration: instant.elapsed(),
+                }
+            })
+            .collect();
+        Ok(DayReport {
+            day: Self::DAY,
+            parse_duration,
+            parts,
+        })
+    }
+}
+
+/// Type-erased [`Solution::run`], so days with different inputs can share one registry.
+pub type Runner = fn(&str, &[Part]) -> Result<DayReport, ParseError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_duration: Duration,
+    pub parts: Vec<PartReport>,
+}
+impl DayReport {
+    pub fn total_duration(&self) -> Duration {
+        self.parse_duration + self.parts.iter().map(|p| p.duration).sum::<Duration>()
+    }
+}
+
+/// Path standing for the standard input wherever an input file is expected.
+pub const STDIN: &str = "-";
+
+/// Reads the puzzle input from `path`, or from the standard input when it is [`STDIN`].
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        read_to_string(path)
+    }
+}
+
+/// Entry point of the per-day binaries: solves both parts and prints the answers.
+///
+/// The input is read from the first argument, `-` for the standard input, or from `default`.
+/// `--verbose` logs the debug messages and timings of the day, see [`crate::logging`].
+pub fn print_solutions<S: Solution>(default: &str) -> ExitCode {
+    let (flags, args) = env::args()
+        .skip(1)
+        .partition::<Vec<_>, _>(|arg| arg == "--verbose" || arg == "-v");
+    logging::init(!flags.is_empty());
+    let filename = args.into_iter().next().unwrap_or_else(|| default.into());
+    let input = match read_input(Path::new(&filename)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: cannot read {filename}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match S::run(&input, &Part::ALL) {
+        Ok(report) => {
+            for report in report.parts {
+                println!("{} solution {}", report.part, format_answer(&report.answer));
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err.report(&filename, &input));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Answer of a part that some inputs have none for, shown as `None` then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+/// Multi-line answers (e.g. pictures drawn on a CRT) start on a fresh line.
+pub fn format_answer(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("\n{answer}")
+    } else {
+        answer.into()
+    }
+}
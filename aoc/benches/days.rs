@@ -0,0 +1,161 @@
+//! Benchmarks every day, timing parsing and each part separately, on the examples and on the
+//! real input.
+//!
+//! `cargo bench -p aoc` ends with a summary table showing the change since the previous run.
+//! To compare two commits, run `cargo bench -p aoc -- --save-baseline NAME` on the first one
+//! and `AOC_BENCH_BASELINE=NAME cargo bench -p aoc` on the second.
+//!
+//! Parts without an expected answer in `answers.toml` are not run on the real input, as some
+//! of them do not finish yet.
+
+use aoc::{
+    answers::{self, Answer},
+    days::{self, DayVisitor},
+};
+use aoc_common::{Part, Solution};
+use criterion::{BenchmarkId, Criterion};
+use std::{
+    env, fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+const REAL_INPUT: &str = "input";
+
+struct Benches<'a> {
+    criterion: &'a mut Criterion,
+    workspace: PathBuf,
+    answers: Vec<Answer>,
+    /// `(group, function, input)` of every benchmark, in the order they were registered.
+    ids: Vec<(String, String, String)>,
+}
+impl Benches<'_> {
+    fn bench<S: Solution>(&mut self, name: &str, input: &str, parts: &[Part]) {
+        let Ok(parsed) = S::parse(input) else {
+            eprintln!("day {:02}: skipping {name}, it does not parse", S::DAY);
+            return;
+        };
+        let group_name = format!("day{:02}", S::DAY);
+        let mut group = self.criterion.benchmark_group(&group_name);
+        group
+            .sample_size(10)
+            .warm_up_time(Duration::from_millis(500));
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+        self.ids
+            .push((group_name.clone(), "parse".into(), name.into()));
+        for &part in parts {
+            group.bench_with_input(
+                BenchmarkId::new(part.to_string(), name),
+                &parsed,
+                |b, parsed| match part {
+                    Part::One => b.iter(|| S::part1(black_box(parsed)).to_string()),
+                    Part::Two => b.iter(|| S::part2(black_box(parsed)).to_string()),
+                },
+            );
+            self.ids
+                .push((group_name.clone(), part.to_string(), name.into()));
+        }
+        group.finish();
+    }
+}
+impl DayVisitor for Benches<'_> {
+    fn visit<S: Solution>(&mut self) {
+        for example in S::EXAMPLES {
+            let parts = Part::ALL
+                .into_iter()
+                .filter(|&part| example.expected(part).is_some())
+                .collect::<Vec<_>>();
+            self.bench::<S>(example.name, example.input, &parts);
+        }
+        let path = self.workspace.join(format!("day-{:02}/input.txt", S::DAY));
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|&part| {
+                self.answers
+                    .iter()
+                    .any(|a| a.day == S::DAY && a.part == part.number() && a.input == path)
+            })
+            .collect::<Vec<_>>();
+        if let Ok(input) = fs::read_to_string(&path) {
+            self.bench::<S>(REAL_INPUT, &input, &parts);
+        }
+    }
+}
+
+fn main() {
+    let started = SystemTime::now();
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut criterion = Criterion::default().configure_from_args();
+    let mut benches = Benches {
+        criterion: &mut criterion,
+        answers: answers::load(&workspace.join("answers.toml")).unwrap_or_default(),
+        workspace,
+        ids: Vec::new(),
+    };
+    days::visit_all(&mut benches);
+    let ids = benches.ids;
+    criterion.final_summary();
+    print_summary(&ids, started);
+}
+
+/// Prints the mean of every benchmark measured since `started`, next to its change against
+/// `AOC_BENCH_BASELINE` (criterion's previous run by default).
+fn print_summary(ids: &[(String, String, String)], started: SystemTime) {
+    let root = criterion_dir();
+    let baseline = env::var("AOC_BENCH_BASELINE").unwrap_or_else(|_| "base".into());
+    println!(
+        "\n{:<8} {:<6} {:<20} {:>12} {:>12} {:>8}",
+        "day", "phase", "input", "mean", baseline, "change"
+    );
+    for (group, function, input) in ids {
+        let dir = root.join(group).join(function).join(input);
+        let estimates = dir.join("new").join("estimates.json");
+        let fresh = fs::metadata(&estimates)
+            .and_then(|meta| meta.modified())
+            .is_ok_and(|modified| modified >= started);
+        let Some(mean) = mean_ns(&estimates).filter(|_| fresh) else {
+            continue;
+        };
+        let base = mean_ns(&dir.join(&baseline).join("estimates.json"));
+        let (base_column, change) = match base {
+            Some(base) => (
+                format_ns(base),
+                format!("{:+.1}%", (mean - base) / base * 100.0),
+            ),
+            None => ("-".into(), "-".into()),
+        };
+        println!(
+            "{group:<8} {function:<6} {input:<20} {:>12} {base_column:>12} {change:>8}",
+            format_ns(mean)
+        );
+    }
+}
+
+/// Where criterion saves its measurements, following the same lookup as criterion itself.
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = env::var_os("CRITERION_HOME") {
+        return home.into();
+    }
+    let target = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"));
+    target.join("criterion")
+}
+
+fn mean_ns(estimates: &Path) -> Option<f64> {
+    let content = fs::read_to_string(estimates).ok()?;
+    let json = serde_json::from_str::<serde_json::Value>(&content).ok()?;
+    json["mean"]["point_estimate"].as_f64()
+}
+
+fn format_ns(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{ns:.1} ns"),
+        ns if ns < 1e6 => format!("{:.1} µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.1} ms", ns / 1e6),
+        ns => format!("{:.2} s", ns / 1e9),
+    }
+}
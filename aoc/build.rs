@@ -0,0 +1,84 @@
+//! Hashes the sources of every day into `SOURCES`, so that cached answers are only reused by the
+//! code that computed them.
+
+use sha2::{Digest, Sha256};
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let workspace = Path::new(&manifest_dir).parent().unwrap();
+    let common = workspace.join("aoc-common");
+    println!("cargo:rerun-if-changed={}", common.display());
+
+    let mut days = fs::read_dir(workspace)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            let day = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("day-")?
+                .parse()
+                .ok()?;
+            Some((day, path))
+        })
+        .collect::<Vec<(u8, PathBuf)>>();
+    days.sort();
+
+    let mut sources = String::from("pub const SOURCES: &[(u8, &str)] = &[\n");
+    for (day, dir) in days {
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let mut hasher = Sha256::new();
+        for path in files(&common).into_iter().chain(files(&dir)) {
+            hasher.update(
+                path.strip_prefix(workspace)
+                    .unwrap()
+                    .to_string_lossy()
+                    .as_bytes(),
+            );
+            hasher.update(fs::read(&path).unwrap());
+        }
+        let hash = hasher
+            .finalize()
+            .iter()
+            .fold(String::new(), |mut hex, byte| {
+                write!(hex, "{byte:02x}").unwrap();
+                hex
+            });
+        writeln!(sources, "    ({day}, \"{hash}\"),").unwrap();
+    }
+    sources += "];\n";
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("sources.rs"),
+        sources,
+    )
+    .unwrap();
+}
+
+/// Every file of the crate in `dir` that can change what it computes, in a stable order.
+///
+/// The puzzle input is left out, it is hashed separately.
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap();
+            if path.is_dir() {
+                if name != "target" {
+                    dirs.push(path);
+                }
+            } else if name != "input.txt" {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
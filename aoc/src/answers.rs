@@ -0,0 +1,202 @@
+use aoc_common::{solution::Runner, Part};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+/// Known answer for one part of a day, as listed in `answers.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// Puzzle input, relative to the directory of `answers.toml`.
+    pub input: PathBuf,
+    pub expected: String,
+}
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    answer: Vec<Answer>,
+}
+
+/// Reads the `[[answer]]` tables of `path`, resolving their inputs against its directory.
+pub fn load(path: &Path) -> Result<Vec<Answer>, String> {
+    let content = read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let file: AnswersFile =
+        toml::from_str(&content).map_err(|err| format!("{}: {err}", path.display()))?;
+    if let Some(answer) = file.answer.iter().find(|a| Part::try_from(a.part).is_err()) {
+        return Err(format!(
+            "{}: day {} has no part {}",
+            path.display(),
+            answer.day,
+            answer.part
+        ));
+    }
+    let root = path.parent().unwrap_or(Path::new(""));
+    Ok(file
+        .answer
+        .into_iter()
+        .map(|answer| Answer {
+            input: root.join(&answer.input),
+            ..answer
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No expected answer is registered, the part was not run.
+    Missing,
+    /// The input could not be read or parsed.
+    Error(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub day: u8,
+    pub part: Part,
+    pub input: Option<PathBuf>,
+    pub status: Status,
+}
+impl CheckResult {
+    pub fn is_regression(&self) -> bool {
+        matches!(self.status, Status::Fail { .. } | Status::Error(_))
+    }
+}
+impl Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = match &self.input {
+            Some(input) => input.display().to_string(),
+            None => "-".into(),
+        };
+        write!(f, "day {:02} {}  ", self.day, self.part)?;
+        match &self.status {
+            Status::Pass => write!(f, "pass     {input}"),
+            Status::Missing => write!(f, "missing  {input}"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL     {input}: expected {expected:?}, got {actual:?}")
+            }
+            Status::Error(err) => write!(f, "ERROR    {input}: {err}"),
+        }
+    }
+}
+
+/// Runs every part of `days` that has an expected answer, one run per day and input file.
+pub fn check(days: &[(u8, Runner)], answers: &[Answer]) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+    for &(day, runner) in days {
+        let mut by_input = BTreeMap::<&Path, Vec<&Answer>>::new();
+        for answer in answers.iter().filter(|answer| answer.day == day) {
+            by_input.entry(&answer.input).or_default().push(answer);
+        }
+        for (input, answers) in by_input {
+            let parts = answers
+                .iter()
+                .filter_map(|answer| Part::try_from(answer.part).ok())
+                .collect::<Vec<_>>();
+            let outcome = read_to_string(input)
+                .map_err(|err| err.to_string())
+                .and_then(|content| runner(&content, &parts).map_err(|err| err.to_string()));
+            for answer in answers {
+                let Ok(part) = Part::try_from(answer.part) else {
+                    continue;
+                };
+                let status = match &outcome {
+                    Err(err) => Status::Error(err.clone()),
+                    Ok(report) => {
+                        let actual = report.parts.iter().find(|p| p.part == part).unwrap();
+                        if actual.answer == answer.expected {
+                            Status::Pass
+                        } else {
+                            Status::Fail {
+                                expected: answer.expected.clone(),
+                                actual: actual.answer.clone(),
+                            }
+                        }
+                    }
+                };
+                results.push(CheckResult {
+                    day,
+                    part,
+                    input: Some(input.into()),
+                    status,
+                });
+            }
+        }
+        for part in Part::ALL {
+            if !results.iter().any(|r| r.day == day && r.part == part) {
+                results.push(CheckResult {
+                    day,
+                    part,
+                    input: None,
+                    status: Status::Missing,
+                });
+            }
+        }
+    }
+    results.sort_by_key(|r| (r.day, r.part));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{
+        solution::{DayReport, PartReport},
+        ParseError,
+    };
+    use std::{env, fs, process, time::Duration};
+
+    /// Answers every part with the length of the input.
+    fn stub_runner(input: &str, parts: &[Part]) -> Result<DayReport, ParseError> {
+        Ok(DayReport {
+            day: 1,
+            parse_duration: Duration::ZERO,
+            parts: parts
+                .iter()
+                .map(|&part| PartReport {
+                    part,
+                    answer: input.len().to_string(),
+                    duration: Duration::ZERO,
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn check_test() {
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), "abc").unwrap();
+        fs::write(
+            dir.join("answers.toml"),
+            "[[answer]]\nday = 1\npart = 1\ninput = \"input.txt\"\nexpected = \"3\"\n\
+             [[answer]]\nday = 1\npart = 2\ninput = \"input.txt\"\nexpected = \"4\"\n\
+             [[answer]]\nday = 2\npart = 1\ninput = \"nope.txt\"\nexpected = \"1\"\n",
+        )
+        .unwrap();
+        let answers = load(&dir.join("answers.toml")).unwrap();
+        let statuses = check(&[(1, stub_runner), (2, stub_runner)], &answers)
+            .into_iter()
+            .map(|r| r.status)
+            .collect::<Vec<_>>();
+        assert_eq!(statuses[0], Status::Pass);
+        assert_eq!(
+            statuses[1],
+            Status::Fail {
+                expected: "4".into(),
+                actual: "3".into()
+            }
+        );
+        assert!(matches!(statuses[2], Status::Error(_)));
+        assert_eq!(statuses[3], Status::Missing);
+    }
+}
@@ -0,0 +1,139 @@
+//! Answers computed by earlier runs, so that unchanged days on unchanged inputs aren't solved
+//! again.
+//!
+//! An answer is only reused for the same day, part and input, by a build whose sources of that
+//! day hash to the same value, see [`crate::days::source_sha256`].
+
+use crate::record::AnswerRecord;
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_PATH: &str = "target/answer-cache.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    record: AnswerRecord,
+    source_sha256: String,
+}
+
+/// Answers saved in a JSON file, see [`Cache::load`].
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    /// Only the latest answer for a day, part and input, older sources never come back.
+    entries: BTreeMap<(u8, u8, String), Entry>,
+}
+impl Cache {
+    /// An empty cache, to be saved at `path`.
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.into(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Reads the answers saved at `path`, none if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let entries = match fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str::<Vec<Entry>>(&content)
+                .map_err(|err| format!("{}: {err}", path.display()))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(format!("{}: {err}", path.display())),
+        };
+        let mut cache = Self::new(path);
+        cache.entries = entries
+            .into_iter()
+            .map(|entry| (key(&entry.record), entry))
+            .collect();
+        Ok(cache)
+    }
+
+    /// Answer to `part` of `day` for the input hashing to `input_sha256`, if the sources hashing
+    /// to `source_sha256` computed it.
+    pub fn get(
+        &self,
+        day: u8,
+        part: Part,
+        input_sha256: &str,
+        source_sha256: &str,
+    ) -> Option<&AnswerRecord> {
+        self.entries
+            .get(&(day, part.number(), input_sha256.into()))
+            .filter(|entry| entry.source_sha256 == source_sha256)
+            .map(|entry| &entry.record)
+    }
+
+    pub fn insert(&mut self, record: AnswerRecord, source_sha256: &str) {
+        let entry = Entry {
+            record,
+            source_sha256: source_sha256.into(),
+        };
+        self.entries.insert(key(&entry.record), entry);
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+        }
+        let entries = self.entries.values().collect::<Vec<_>>();
+        fs::write(&self.path, serde_json::to_string_pretty(&entries).unwrap())
+            .map_err(|err| format!("{}: {err}", self.path.display()))
+    }
+}
+
+fn key(record: &AnswerRecord) -> (u8, u8, String) {
+    (record.day, record.part, record.input_sha256.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    fn record(answer: &str) -> AnswerRecord {
+        AnswerRecord {
+            day: 16,
+            part: 2,
+            answer: answer.into(),
+            duration_ns: 1_000_000,
+            input_sha256: "abc".into(),
+        }
+    }
+
+    #[test]
+    fn cache_test() {
+        let dir = env::temp_dir().join(format!("aoc-cache-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("answers.json");
+
+        let mut cache = Cache::load(&path).unwrap();
+        assert_eq!(cache.get(16, Part::Two, "abc", "v1"), None);
+        cache.insert(record("1707"), "v1");
+        cache.save().unwrap();
+
+        let mut cache = Cache::load(&path).unwrap();
+        assert_eq!(cache.get(16, Part::Two, "abc", "v1"), Some(&record("1707")));
+        assert_eq!(cache.get(16, Part::Two, "abc", "v2"), None);
+        assert_eq!(cache.get(16, Part::Two, "def", "v1"), None);
+        assert_eq!(cache.get(16, Part::One, "abc", "v1"), None);
+
+        cache.insert(record("1708"), "v2");
+        assert_eq!(cache.get(16, Part::Two, "abc", "v1"), None);
+        assert_eq!(cache.get(16, Part::Two, "abc", "v2"), Some(&record("1708")));
+    }
+
+    #[test]
+    fn corrupt_test() {
+        let path = env::temp_dir().join(format!("aoc-cache-corrupt-{}.json", process::id()));
+        fs::write(&path, "{").unwrap();
+        assert!(Cache::load(&path)
+            .unwrap_err()
+            .starts_with(&path.display().to_string()));
+    }
+}
@@ -0,0 +1,87 @@
+use aoc_common::{animation::Animation, gen::Generator, solution::Runner, Solution};
+
+/// Needs every day's concrete [`Solution`] type, e.g. to time parsing on its own.
+pub trait DayVisitor {
+    fn visit<S: Solution>(&mut self);
+}
+
+macro_rules! days {
+    ($($krate:ident::$day:ident),* $(,)?) => {
+        /// Every solved day, in calendar order.
+        pub const DAYS: &[(u8, Runner)] = &[$(($krate::$day::DAY, $krate::$day::run)),*];
+
+        /// Input generator of every solved day with the smallest size it generates, in calendar
+        /// order.
+        pub const GENERATORS: &[(u8, Generator, usize)] =
+            &[$(($krate::$day::DAY, $krate::gen::generate, $krate::gen::MIN_SIZE)),*];
+
+        /// Calls `visitor` with every solved day, in calendar order.
+        pub fn visit_all<V: DayVisitor>(visitor: &mut V) {
+            $(visitor.visit::<$krate::$day>();)*
+        }
+    };
+}
+
+days![
+    day_01::Day01,
+    day_02::Day02,
+    day_03::Day03,
+    day_04::Day04,
+    day_05::Day05,
+    day_06::Day06,
+    day_07::Day07,
+    day_08::Day08,
+    day_09::Day09,
+    day_10::Day10,
+    day_11::Day11,
+    day_12::Day12,
+    day_13::Day13,
+    day_14::Day14,
+    day_15::Day15,
+    day_16::Day16,
+    day_17::Day17,
+    day_18::Day18,
+    day_19::Day19,
+    day_20::Day20,
+    day_21::Day21,
+    day_22::Day22,
+    day_23::Day23,
+    day_24::Day24,
+];
+
+include!(concat!(env!("OUT_DIR"), "/sources.rs"));
+
+/// SHA-256 of the sources of `day` and of the code it shares with the other days, as built.
+pub fn source_sha256(day: u8) -> Option<&'static str> {
+    SOURCES
+        .iter()
+        .find_map(|&(d, hash)| (d == day).then_some(hash))
+}
+
+pub fn find(day: u8) -> Option<Runner> {
+    DAYS.iter()
+        .find_map(|&(d, runner)| (d == day).then_some(runner))
+}
+
+pub fn find_generator(day: u8) -> Option<(Generator, usize)> {
+    GENERATORS
+        .iter()
+        .find_map(|&(d, generate, min_size)| (d == day).then_some((generate, min_size)))
+}
+
+/// Days whose simulation can be played with `aoc animate`.
+pub const ANIMATIONS: &[(u8, Animation)] = &[
+    (9, day_09::animation),
+    (10, day_10::animation),
+    (14, day_14::animation),
+    (17, day_17::animation),
+    (20, day_20::animation),
+    (23, day_23::animation),
+    (24, day_24::animation),
+];
+
+pub fn find_animation(day: u8) -> Option<Animation> {
+    ANIMATIONS
+        .iter()
+        .find_map(|&(d, animation)| (d == day).then_some(animation))
+}
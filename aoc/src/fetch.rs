@@ -0,0 +1,207 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the value of the `session` cookie of adventofcode.com.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!(
+    "aoc-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (fetches one input per day and caches it)"
+);
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither [`SESSION_ENV`] nor the config file provide a session token.
+    MissingSession(Option<PathBuf>),
+    Io(PathBuf, io::Error),
+    Http(String, ureq::Error),
+}
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession(config) => {
+                write!(f, "no session token, set {SESSION_ENV}")?;
+                match config {
+                    Some(config) => write!(f, " or write it to {}", config.display()),
+                    None => Ok(()),
+                }
+            }
+            FetchError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            FetchError::Http(url, ureq::Error::StatusCode(404)) => {
+                write!(f, "{url}: puzzle not found, it may not be unlocked yet")
+            }
+            FetchError::Http(url, ureq::Error::StatusCode(400 | 500)) => {
+                write!(
+                    f,
+                    "{url}: request rejected, the session token may have expired"
+                )
+            }
+            FetchError::Http(url, err) => write!(f, "{url}: {err}"),
+        }
+    }
+}
+impl Error for FetchError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The input was saved by an earlier fetch and was left alone.
+    Cached,
+}
+
+/// Downloads puzzle inputs, see [`Fetcher::fetch`].
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+}
+impl Fetcher {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: None,
+        }
+    }
+
+    /// Uses `session` instead of reading it from the config file.
+    pub fn with_session(self, session: &str) -> Self {
+        Self {
+            session: Some(session.into()),
+            ..self
+        }
+    }
+
+    /// Saves the input of `year`/`day` to `path`, unless an earlier fetch already did.
+    ///
+    /// Inputs never change, so they are downloaded at most once to spare the server.
+    pub fn fetch(&self, year: u16, day: u8, path: &Path) -> Result<Fetched, FetchError> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.download(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| FetchError::Io(dir.into(), err))?;
+        }
+        fs::write(path, input).map_err(|err| FetchError::Io(path.into(), err))?;
+        Ok(Fetched::Downloaded)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => config_session()?,
+        };
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+        agent
+            .get(&url)
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| FetchError::Http(url, err))
+    }
+}
+
+/// Reads the session token from `$XDG_CONFIG_HOME/aoc/session` (`~/.config/aoc/session` by
+/// default).
+fn config_session() -> Result<String, FetchError> {
+    let config = config_path();
+    match config.as_deref().map(fs::read_to_string) {
+        Some(Ok(session)) if !session.trim().is_empty() => Ok(session.trim().into()),
+        Some(Err(err)) if err.kind() != io::ErrorKind::NotFound => {
+            Err(FetchError::Io(config.unwrap(), err))
+        }
+        _ => Err(FetchError::MissingSession(config)),
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        process,
+        thread::{self, JoinHandle},
+    };
+
+    /// Answers a single request with `status` and `body`, returning the request it received.
+    fn stub_server(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (base_url, handle)
+    }
+
+    fn temp_input(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("day-07").join("input.txt")
+    }
+
+    #[test]
+    fn fetch_test() {
+        let (base_url, server) = stub_server("200 OK", "1\n2\n3\n");
+        let path = temp_input("fetch");
+        let fetcher = Fetcher::new(&base_url).with_session("abc");
+        assert_eq!(fetcher.fetch(2022, 7, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2022/day/7/input http/1.1"));
+        assert!(request.contains("cookie: session=abc"));
+
+        // the server is gone, a second fetch must not try to reach it
+        assert_eq!(fetcher.fetch(2022, 7, &path).unwrap(), Fetched::Cached);
+    }
+
+    #[test]
+    fn not_found_test() {
+        let (base_url, server) = stub_server("404 Not Found", "");
+        let path = temp_input("not-found");
+        let err = Fetcher::new(&base_url)
+            .with_session("abc")
+            .fetch(2022, 7, &path)
+            .unwrap_err();
+        server.join().unwrap();
+        assert!(matches!(
+            err,
+            FetchError::Http(_, ureq::Error::StatusCode(404))
+        ));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,248 @@
+//! Solves many days and parts at once, see [`run_jobs`].
+
+use aoc_common::{
+    solution::{DayReport, Runner},
+    ParseError, Part,
+};
+use std::{
+    collections::VecDeque,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Condvar, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// One part of a day to solve.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub day: u8,
+    pub part: Part,
+    pub runner: Runner,
+    /// Where the input was read from, to point at parse errors.
+    pub path: String,
+    pub input: Arc<str>,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(DayReport),
+    Invalid(ParseError),
+    TimedOut,
+    Panicked,
+}
+
+#[derive(Debug)]
+pub struct Finished {
+    pub job: Job,
+    pub outcome: Outcome,
+    /// Wall time from starting the job until it was solved or given up on.
+    pub elapsed: Duration,
+}
+
+/// Bounds how many parts are solved at the same time, see [`Slots::acquire`].
+#[derive(Debug)]
+pub struct Slots {
+    free: Mutex<usize>,
+    released: Condvar,
+}
+impl Slots {
+    pub fn new(count: usize) -> Arc<Self> {
+        Arc::new(Self {
+            free: Mutex::new(count),
+            released: Condvar::new(),
+        })
+    }
+
+    /// Waits up to `timeout` for a free slot, which is given back when the [`Slot`] is dropped.
+    pub fn acquire(self: &Arc<Self>, timeout: Duration) -> Option<Slot> {
+        let free = self.free.lock().unwrap();
+        let (mut free, _) = self
+            .released
+            .wait_timeout_while(free, timeout, |free| *free == 0)
+            .unwrap();
+        if *free == 0 {
+            return None;
+        }
+        *free -= 1;
+        Some(Slot(Arc::clone(self)))
+    }
+
+    /// Takes a free slot if there is one, without waiting.
+    pub fn try_acquire(self: &Arc<Self>) -> Option<Slot> {
+        self.acquire(Duration::ZERO)
+    }
+}
+
+/// A slot taken from [`Slots`], held for as long as its part is being solved.
+#[derive(Debug)]
+pub struct Slot(Arc<Slots>);
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *self.0.free.lock().unwrap() += 1;
+        self.0.released.notify_one();
+    }
+}
+
+/// Solves `part` of `input` on a thread of its own, giving up on it after `timeout`.
+///
+/// A part can't be interrupted, so one that times out keeps running in the background until it
+/// finishes or the process exits, and keeps `slot` taken until then.
+pub fn run_part(
+    runner: Runner,
+    input: &Arc<str>,
+    part: Part,
+    timeout: Duration,
+    slot: Slot,
+) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let input = Arc::clone(input);
+    thread::spawn(move || {
+        let _slot = slot;
+        let _ = sender.send(runner(&input, &[part]));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(report)) => Outcome::Solved(report),
+        Ok(Err(err)) => Outcome::Invalid(err),
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => Outcome::Panicked,
+    }
+}
+
+/// Solves `jobs` on `threads` threads, each part allowed `timeout` before it is reported as
+/// timed out, and returns them in the order they finished.
+///
+/// Parts still running after they timed out count against `threads`, so a later job may time
+/// out waiting for one of them to finish.
+pub fn run_jobs(jobs: Vec<Job>, threads: usize, timeout: Duration) -> Vec<Finished> {
+    let queue = Mutex::new(VecDeque::from(jobs));
+    let finished = Mutex::new(Vec::new());
+    let slots = Slots::new(threads.max(1));
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| loop {
+                let Some(job) = queue.lock().unwrap().pop_front() else {
+                    break;
+                };
+                let instant = Instant::now();
+                let outcome = match slots.acquire(timeout) {
+                    Some(slot) => {
+                        let timeout = timeout.saturating_sub(instant.elapsed());
+                        run_part(job.runner, &job.input, job.part, timeout, slot)
+                    }
+                    None => Outcome::TimedOut,
+                };
+                finished.lock().unwrap().push(Finished {
+                    job,
+                    outcome,
+                    elapsed: instant.elapsed(),
+                });
+            });
+        }
+    });
+    finished.into_inner().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solution::PartReport, Solution};
+
+    fn sleep(input: &str, parts: &[Part]) -> Result<DayReport, ParseError> {
+        thread::sleep(Duration::from_millis(input.parse().unwrap()));
+        Ok(DayReport {
+            day: 2,
+            parse_duration: Duration::ZERO,
+            parts: vec![PartReport {
+                part: parts[0],
+                answer: input.into(),
+                duration: Duration::ZERO,
+            }],
+        })
+    }
+
+    fn panic(_: &str, _: &[Part]) -> Result<DayReport, ParseError> {
+        panic!("not solved")
+    }
+
+    fn job(day: u8, part: Part, runner: Runner, input: &str) -> Job {
+        Job {
+            day,
+            part,
+            runner,
+            path: format!("day-{day:02}/input.txt"),
+            input: input.into(),
+        }
+    }
+
+    #[test]
+    fn run_jobs_test() {
+        let jobs = vec![
+            job(1, Part::One, day_01::Day01::run, "1\n\n2\n"),
+            job(1, Part::Two, day_01::Day01::run, "1\nx\n"),
+            job(2, Part::One, sleep, "200"),
+            job(2, Part::Two, sleep, "10"),
+            job(3, Part::One, panic, ""),
+        ];
+        let mut finished = run_jobs(jobs, 2, Duration::from_millis(100));
+        assert_eq!(finished.len(), 5);
+        finished.sort_by_key(|finished| (finished.job.day, finished.job.part));
+        let outcomes = finished
+            .iter()
+            .map(|finished| match &finished.outcome {
+                Outcome::Solved(report) => report.parts[0].answer.clone(),
+                Outcome::Invalid(err) => format!("invalid at line {}", err.line),
+                Outcome::TimedOut => "timed out".into(),
+                Outcome::Panicked => "panicked".into(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            ["2", "invalid at line 2", "timed out", "10", "panicked"]
+        );
+    }
+
+    #[test]
+    fn timed_out_test() {
+        // the first part keeps its thread after timing out, the second waits for it
+        let jobs = vec![
+            job(1, Part::One, sleep, "300"),
+            job(1, Part::Two, sleep, "10"),
+        ];
+        let mut finished = run_jobs(jobs, 1, Duration::from_millis(200));
+        finished.sort_by_key(|finished| finished.job.part);
+        assert!(matches!(finished[0].outcome, Outcome::TimedOut));
+        assert!(matches!(finished[1].outcome, Outcome::Solved(_)));
+        assert!(finished[1].elapsed >= Duration::from_millis(90));
+
+        let jobs = vec![
+            job(1, Part::One, sleep, "300"),
+            job(1, Part::Two, sleep, "10"),
+        ];
+        let finished = run_jobs(jobs, 1, Duration::from_millis(50));
+        assert!(finished
+            .iter()
+            .all(|finished| matches!(finished.outcome, Outcome::TimedOut)));
+    }
+
+    #[test]
+    fn slots_test() {
+        let slots = Slots::new(1);
+        let slot = slots.try_acquire().unwrap();
+        assert!(slots.try_acquire().is_none());
+        assert!(slots.acquire(Duration::from_millis(10)).is_none());
+        drop(slot);
+        assert!(slots.try_acquire().is_some());
+    }
+
+    #[test]
+    fn parallel_test() {
+        let jobs = (0..4)
+            .map(|day| job(day, Part::One, sleep, "100"))
+            .collect();
+        let instant = Instant::now();
+        assert_eq!(run_jobs(jobs, 4, Duration::from_secs(5)).len(), 4);
+        assert!(instant.elapsed() < Duration::from_millis(300));
+    }
+}
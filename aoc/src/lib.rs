@@ -0,0 +1,8 @@
+pub mod answers;
+pub mod cache;
+pub mod days;
+pub mod fetch;
+pub mod jobs;
+pub mod record;
+pub mod scaffold;
+pub mod serve;
@@ -0,0 +1,589 @@
+use aoc::{
+    answers::{self, Status},
+    cache::{self, Cache},
+    days,
+    fetch::{self, Fetched, Fetcher},
+    jobs::{self, Finished, Job, Outcome},
+    record::{sha256_hex, AnswerRecord},
+    scaffold,
+    serve::{self, Server},
+};
+use aoc_common::{
+    animation::{self, Player},
+    gen, logging,
+    solution::{format_answer, read_input},
+    Part,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{
+    cmp::Reverse,
+    fs::File,
+    io::{self, BufWriter},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Logs debug messages and the time spent in every phase to the standard error
+    ///
+    /// `AOC_LOG` selects what to log instead, e.g. `AOC_LOG=day_19=trace`.
+    #[arg(long, short, global = true)]
+    verbose: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a single day, or every day, with parts running in parallel, and reports a table of
+    /// the parts sorted from the slowest
+    Run {
+        /// Day to run, all days are run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to run, both parts are run when omitted
+        #[arg(long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input, `-` for the standard input, defaults to `day-NN/input.txt`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Output format, `json` prints one object per solved part and line
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Parts solved at the same time, defaults to the number of CPUs
+        ///
+        /// Parts that timed out count until they finish, as they can't be stopped.
+        #[arg(long, short)]
+        jobs: Option<NonZeroUsize>,
+        /// Seconds a part may take before it is reported as timed out, waiting for a free job
+        /// included
+        #[arg(long, value_parser = seconds)]
+        timeout: Option<Duration>,
+        /// Solves every part again instead of reusing the answers cached for unchanged days and
+        /// inputs
+        #[arg(long)]
+        force: bool,
+        /// Answers of earlier runs
+        #[arg(long, default_value = cache::DEFAULT_PATH)]
+        cache: PathBuf,
+    },
+    /// Checks every day against the expected answers and exits non-zero on regressions
+    Check {
+        /// Registry of expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Downloads the puzzle input of a day into `day-NN/input.txt`
+    ///
+    /// The session token is read from `--session`, `AOC_SESSION` or `~/.config/aoc/session`.
+    /// Inputs that were already fetched are never downloaded again. Days that do not exist yet
+    /// are created as with `aoc new`.
+    Fetch {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = 2022, value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        /// Server to download from, e.g. a local stub for testing
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+        /// Value of the `session` cookie of adventofcode.com
+        #[arg(long, env = fetch::SESSION_ENV, hide_env_values = true)]
+        session: Option<String>,
+    },
+    /// Creates `day-NN` from the standard day template and registers it with the runner
+    New {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Plays the simulation of a day in the terminal, or exports it
+    ///
+    /// While playing, enter pauses or resumes, `s` shows the next frame, `+` and `-` change the
+    /// speed and `q` quits.
+    Animate {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle input, `-` for the standard input, defaults to `day-NN/input.txt`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Frames per second
+        #[arg(long, default_value_t = 10.0, value_parser = positive)]
+        fps: f64,
+        /// Writes an asciicast recording to this file instead of playing
+        #[arg(long, conflicts_with = "frames")]
+        cast: Option<PathBuf>,
+        /// Writes every frame to its own text file in this directory instead of playing
+        #[arg(long)]
+        frames: Option<PathBuf>,
+        /// Stops after this many frames
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+    /// Answers `POST /days/{day}/parts/{part}` requests carrying a puzzle input with JSON
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = serve::DEFAULT_ADDR)]
+        addr: String,
+        /// Largest input accepted, in bytes
+        #[arg(long, default_value_t = serve::DEFAULT_MAX_INPUT)]
+        max_input: usize,
+        /// Seconds a part may take before its request fails, 30 by default
+        #[arg(long, value_parser = seconds)]
+        timeout: Option<Duration>,
+        /// Requests answered at the same time, defaults to the number of CPUs
+        ///
+        /// Others are turned away with 503. Parts that timed out count until they finish, as
+        /// they can't be stopped.
+        #[arg(long, short)]
+        jobs: Option<NonZeroUsize>,
+    },
+    /// Prints a random input for a day, the same one for the same seed and size
+    ///
+    /// What `size` counts depends on the day, e.g. elves on day 1 or valves on day 16. Sizes
+    /// below the smallest a day generates are raised to it, with a note on the standard error.
+    Gen {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long)]
+        seed: u64,
+        #[arg(long)]
+        size: usize,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(cli.verbose);
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            jobs,
+            timeout,
+            force,
+            cache,
+        } => {
+            let options = RunOptions {
+                format,
+                threads: jobs
+                    .or_else(|| thread::available_parallelism().ok())
+                    .map_or(1, NonZeroUsize::get),
+                timeout: timeout.unwrap_or(Duration::MAX),
+                force,
+            };
+            let cache = Cache::load(&cache).unwrap_or_else(|err| {
+                eprintln!("ignoring the cache: {err}");
+                Cache::new(&cache)
+            });
+            run(day, part, input, &options, cache)
+        }
+        Command::Check { answers } => check(&answers),
+        Command::Fetch {
+            day,
+            year,
+            base_url,
+            session,
+        } => fetch(day, year, &base_url, session.as_deref()),
+        Command::New { day } => new(day),
+        Command::Animate {
+            day,
+            input,
+            fps,
+            cast,
+            frames,
+            limit,
+        } => animate(day, input, fps, cast, frames, limit),
+        Command::Serve {
+            addr,
+            max_input,
+            timeout,
+            jobs,
+        } => serve(&addr, max_input, timeout, jobs),
+        Command::Gen { day, seed, size } => generate(day, seed, size),
+    }
+}
+
+/// How `aoc run` solves and reports the selected parts.
+struct RunOptions {
+    format: Format,
+    threads: usize,
+    timeout: Duration,
+    /// Solves the parts whose answers are cached too.
+    force: bool,
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    options: &RunOptions,
+    mut cache: Cache,
+) -> ExitCode {
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part).unwrap()],
+        None => Part::ALL.to_vec(),
+    };
+    let selected = match day {
+        Some(day) => match days::find(day) {
+            Some(runner) => vec![(day, runner)],
+            None => {
+                eprintln!("day {day} is not solved yet");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => days::DAYS.to_vec(),
+    };
+
+    let mut failed = false;
+    let mut queued = Vec::new();
+    let mut cached = Vec::new();
+    for (day, runner) in selected {
+        let path = input.clone().unwrap_or_else(|| default_input(day));
+        let content: Arc<str> = match read_input(&path) {
+            Ok(content) => content.into(),
+            Err(err) => {
+                eprintln!("day {day:02}: failed to read {}: {err}", path.display());
+                failed = true;
+                continue;
+            }
+        };
+        let input_sha256 = sha256_hex(&content);
+        for &part in &parts {
+            let source_sha256 = days::source_sha256(day).unwrap_or_default();
+            match cache.get(day, part, &input_sha256, source_sha256) {
+                Some(record) if !options.force => cached.push(record.clone()),
+                _ => queued.push(Job {
+                    day,
+                    part,
+                    runner,
+                    path: path.display().to_string(),
+                    input: Arc::clone(&content),
+                }),
+            }
+        }
+    }
+    let instant = Instant::now();
+    let finished = jobs::run_jobs(queued, options.threads, options.timeout);
+    let wall_time = instant.elapsed();
+
+    for Finished { job, outcome, .. } in &finished {
+        match outcome {
+            Outcome::Invalid(err) => eprintln!("{}", err.report(&job.path, &job.input)),
+            Outcome::Panicked => eprintln!("day {:02} {}: panicked", job.day, job.part),
+            Outcome::Solved(_) | Outcome::TimedOut => {}
+        }
+        failed |= !matches!(outcome, Outcome::Solved(_));
+    }
+    let solved = finished
+        .iter()
+        .filter_map(|Finished { job, outcome, .. }| match outcome {
+            Outcome::Solved(report) => Some(AnswerRecord::from_report(report, &job.input)),
+            _ => None,
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+    if !solved.is_empty() {
+        for record in &solved {
+            cache.insert(
+                record.clone(),
+                days::source_sha256(record.day).unwrap_or_default(),
+            );
+        }
+        if let Err(err) = cache.save() {
+            eprintln!("cannot save the cache: {err}");
+        }
+    }
+
+    match options.format {
+        Format::Text => {
+            let total = finished.iter().map(|finished| finished.elapsed).sum();
+            let mut rows = finished.iter().map(Row::solved).collect::<Vec<_>>();
+            rows.extend(cached.iter().map(Row::cached));
+            rows.sort_by_key(|row| Reverse(row.duration));
+            print_table(&rows);
+            println!(
+                "total {}, {} wall time, {} cached",
+                format_duration(total),
+                format_duration(wall_time),
+                cached.len()
+            );
+        }
+        Format::Json => {
+            let mut records = solved;
+            records.extend(cached);
+            records.sort_by_key(|record| (record.day, record.part));
+            for record in records {
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn check(path: &Path) -> ExitCode {
+    let answers = match answers::load(path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let results = answers::check(days::DAYS, &answers);
+    for result in &results {
+        println!("{result}");
+    }
+    let count = |status: fn(&Status) -> bool| results.iter().filter(|r| status(&r.status)).count();
+    let regressions = results.iter().filter(|r| r.is_regression()).count();
+    println!(
+        "{} passed, {regressions} failed, {} missing",
+        count(|s| *s == Status::Pass),
+        count(|s| *s == Status::Missing)
+    );
+
+    if regressions > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(day: u8, year: u16, base_url: &str, session: Option<&str>) -> ExitCode {
+    let path = default_input(day);
+    let fetcher = match session {
+        Some(session) => Fetcher::new(base_url).with_session(session),
+        None => Fetcher::new(base_url),
+    };
+    match fetcher.fetch(year, day, &path) {
+        Ok(Fetched::Downloaded) => println!("saved {}", path.display()),
+        Ok(Fetched::Cached) => {
+            println!("{} already exists, not fetching it again", path.display())
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    // the workspace takes every `day-*` directory as a member, it must be a crate
+    match path.parent() {
+        Some(dir) if !dir.join("Cargo.toml").exists() => new(day),
+        _ => ExitCode::SUCCESS,
+    }
+}
+
+fn new(day: u8) -> ExitCode {
+    match scaffold::new_day(Path::new(""), day) {
+        Ok(changed) => {
+            for path in changed {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn animate(
+    day: u8,
+    input: Option<PathBuf>,
+    fps: f64,
+    cast: Option<PathBuf>,
+    frames: Option<PathBuf>,
+    limit: Option<usize>,
+) -> ExitCode {
+    let Some(parse) = days::find_animation(day) else {
+        eprintln!("day {day} has no animation");
+        return ExitCode::FAILURE;
+    };
+    let path = input.unwrap_or_else(|| default_input(day));
+    let content = match read_input(&path) {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("day {day:02}: failed to read {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let animation = match parse(&content) {
+        Ok(animation) => animation,
+        Err(err) => {
+            eprintln!("{}", err.report(&path.display().to_string(), &content));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match (cast, frames) {
+        (Some(cast), _) => File::create(&cast).and_then(|file| {
+            let frames = animation::frames(animation, limit);
+            let written = animation::write_asciicast(frames, fps, &mut BufWriter::new(file))?;
+            println!("wrote {written} frames to {}", cast.display());
+            Ok(())
+        }),
+        (None, Some(dir)) => animation::write_frame_dump(animation::frames(animation, limit), &dir)
+            .map(|written| {
+                println!("wrote {written} frames to {}", dir.display());
+            }),
+        (None, None) => {
+            let player = Player::new(fps);
+            let mut player = match limit {
+                Some(limit) => player.with_limit(limit),
+                None => player,
+            };
+            let controls = animation::stdin_controls();
+            player
+                .play(animation, &mut io::stdout().lock(), &controls)
+                .map(|_| ())
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(
+    addr: &str,
+    max_input: usize,
+    timeout: Option<Duration>,
+    jobs: Option<NonZeroUsize>,
+) -> ExitCode {
+    let server = match Server::bind(addr, days::DAYS) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut server = server
+        .with_max_input(max_input)
+        .with_timeout(timeout.unwrap_or(serve::DEFAULT_TIMEOUT));
+    if let Some(jobs) = jobs {
+        server = server.with_max_solves(jobs.get());
+    }
+    if let Some(addr) = server.addr() {
+        println!("listening on http://{addr}");
+    }
+    server.run();
+    ExitCode::SUCCESS
+}
+
+fn generate(day: u8, seed: u64, size: usize) -> ExitCode {
+    let Some((generate, min_size)) = days::find_generator(day) else {
+        eprintln!("day {day} is not solved yet");
+        return ExitCode::FAILURE;
+    };
+    if size < min_size {
+        eprintln!("note: size raised to {min_size}, the smallest day {day} generates");
+    }
+    print!("{}", generate(&mut gen::rng(seed), size));
+    ExitCode::SUCCESS
+}
+
+/// Parses a number of frames or seconds, which anything but a finite positive number isn't.
+fn positive(arg: &str) -> Result<f64, String> {
+    match arg.parse::<f64>() {
+        Ok(value) if value > 0.0 && value.is_finite() => Ok(value),
+        Ok(_) => Err("expected a positive number".into()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Parses a positive number of seconds, as long as it fits in a [`Duration`].
+fn seconds(arg: &str) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(positive(arg)?).map_err(|_| "too many seconds".into())
+}
+
+fn default_input(day: u8) -> PathBuf {
+    Path::new(&format!("day-{day:02}")).join("input.txt")
+}
+
+/// A line of the table printed by `aoc run`.
+struct Row {
+    day: u8,
+    part: u8,
+    /// Time spent in the part alone, as timed by the run that computed cached answers, or until
+    /// it was given up on for parts that have no answer.
+    duration: Duration,
+    cached: bool,
+    result: String,
+}
+impl Row {
+    fn solved(
+        Finished {
+            job,
+            outcome,
+            elapsed,
+        }: &Finished,
+    ) -> Self {
+        let (duration, result) = match outcome {
+            Outcome::Solved(report) => (
+                report.parts[0].duration,
+                format_answer(&report.parts[0].answer),
+            ),
+            Outcome::Invalid(_) => (*elapsed, "invalid input".into()),
+            Outcome::TimedOut => (*elapsed, "timed out".into()),
+            Outcome::Panicked => (*elapsed, "panicked".into()),
+        };
+        Self {
+            day: job.day,
+            part: job.part.number(),
+            duration,
+            cached: false,
+            result,
+        }
+    }
+
+    fn cached(record: &AnswerRecord) -> Self {
+        Self {
+            day: record.day,
+            part: record.part,
+            duration: Duration::from_nanos(record.duration_ns),
+            cached: true,
+            result: format_answer(&record.answer),
+        }
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    for row in rows {
+        let separator = if row.result.starts_with('\n') {
+            ""
+        } else {
+            "  "
+        };
+        println!(
+            "day {:02} part{} {:>12} {:6}{separator}{}",
+            row.day,
+            row.part,
+            format_duration(row.duration),
+            if row.cached { "cached" } else { "" },
+            row.result
+        );
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
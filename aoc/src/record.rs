@@ -0,0 +1,65 @@
+use aoc_common::solution::DayReport;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+/// Answer to one part, in the shape emitted by `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub duration_ns: u64,
+    /// Identifies the input the answer belongs to, without publishing the input itself.
+    pub input_sha256: String,
+}
+impl AnswerRecord {
+    pub fn from_report(report: &DayReport, input: &str) -> Vec<Self> {
+        let input_sha256 = sha256_hex(input);
+        report
+            .parts
+            .iter()
+            .map(|part| AnswerRecord {
+                day: report.day,
+                part: part.part.number(),
+                answer: part.answer.clone(),
+                duration_ns: part.duration.as_nanos().try_into().unwrap_or(u64::MAX),
+                input_sha256: input_sha256.clone(),
+            })
+            .collect()
+    }
+}
+
+pub fn sha256_hex(input: &str) -> String {
+    Sha256::digest(input)
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solution::PartReport, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn json_test() {
+        let report = DayReport {
+            day: 10,
+            parse_duration: Duration::from_nanos(5),
+            parts: vec![PartReport {
+                part: Part::Two,
+                answer: "#.\n.#".into(),
+                duration: Duration::from_nanos(1234),
+            }],
+        };
+        let records = AnswerRecord::from_report(&report, "abc");
+        assert_eq!(
+            serde_json::to_string(&records[0]).unwrap(),
+            r##"{"day":10,"part":2,"answer":"#.\n.#","duration_ns":1234,"input_sha256":"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"}"##
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day-{NN}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+"#;
+
+const MAIN_RS: &str = r#"use day_{NN}::Day{NN};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_common::solution::print_solutions::<Day{NN}>("input.txt")
+}
+"#;
+
+const LIB_RS: &str = r#"pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution};
+use std::fmt::Display;
+
+pub struct Day{NN};
+impl Solution for Day{NN} {
+    const DAY: u8 = {N};
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))];
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input) -> impl Display {
+        part1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> impl Display {
+        part2(lines)
+    }
+}
+
+fn part1(_lines: &[String]) -> usize {
+    0
+}
+
+fn part2(_lines: &[String]) -> usize {
+    0
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(String::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, part1, part2, Day{NN}};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test() {
+        let lines = parse(fixture!("test-input")).unwrap();
+        assert_eq!(0, part1(&lines));
+    }
+
+    #[test]
+    fn part2_test() {
+        let lines = parse(fixture!("test-input")).unwrap();
+        assert_eq!(0, part2(&lines));
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day{NN}>();
+    }
+}
+"#;
+
+const GEN_RS: &str = r#"//! One line per unit of `size`.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n", rng.gen_range(0..100)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day{NN};
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day{NN}>(generate, 10);
+    }
+}
+"#;
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{NN}", &format!("{day:02}"))
+        .replace("{N}", &day.to_string())
+}
+
+/// Creates `day-NN` in `workspace` with the layout shared by every day, and registers it with
+/// the workspace and with the `aoc` runner.
+///
+/// `day-NN` may already exist if it only holds the `input.txt` saved by `aoc fetch`.
+///
+/// Returns the files that were created or modified.
+pub fn new_day(workspace: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day-{day:02}");
+    let dir = workspace.join(&name);
+    if !is_vacant(&dir)? {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let manifest = workspace.join("Cargo.toml");
+    let runner_manifest = workspace.join("aoc").join("Cargo.toml");
+    let registry = workspace.join("aoc").join("src").join("days.rs");
+    // edit everything in memory first, so a bad workspace leaves no half-made day behind
+    let edits = [
+        (&manifest, add_member(&read(&manifest)?, &name)?),
+        (
+            &runner_manifest,
+            add_dependency(&read(&runner_manifest)?, &name)?,
+        ),
+        (&registry, add_day(&read(&registry)?, day)?),
+    ];
+
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TOML, day)),
+        (dir.join("src").join("main.rs"), render(MAIN_RS, day)),
+        (dir.join("src").join("lib.rs"), render(LIB_RS, day)),
+        (dir.join("src").join("gen.rs"), render(GEN_RS, day)),
+        (dir.join("test-input.txt"), String::new()),
+    ];
+    fs::create_dir_all(dir.join("src")).map_err(|err| format!("{}: {err}", dir.display()))?;
+    let mut changed = Vec::new();
+    for (path, content) in files.into_iter().chain(
+        edits
+            .into_iter()
+            .flat_map(|(path, content)| content.map(|content| (path.clone(), content))),
+    ) {
+        fs::write(&path, content).map_err(|err| format!("{}: {err}", path.display()))?;
+        changed.push(path);
+    }
+    Ok(changed)
+}
+
+/// Whether `dir` is missing or holds nothing but the puzzle input.
+fn is_vacant(dir: &Path) -> Result<bool, String> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(true),
+        Err(err) => return Err(format!("{}: {err}", dir.display())),
+    };
+    for entry in entries {
+        let entry = entry.map_err(|err| format!("{}: {err}", dir.display()))?;
+        if entry.file_name() != "input.txt" {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Adds `name` to the `members` of the workspace manifest, unless one of them already matches.
+fn add_member(manifest: &str, name: &str) -> Result<Option<String>, String> {
+    let parsed: toml::Table = toml::from_str(manifest).map_err(|err| err.to_string())?;
+    let members = parsed
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+        .ok_or("the workspace manifest has no members")?;
+    let matches = |member: &str| match member.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => member == name,
+    };
+    if members.iter().filter_map(|m| m.as_str()).any(matches) {
+        return Ok(None);
+    }
+    let start = manifest.find("members").unwrap();
+    let end = start + manifest[start..].find(']').unwrap();
+    let separator = if members.is_empty() { "" } else { ", " };
+    Ok(Some(format!(
+        "{}{separator}\"{name}\"{}",
+        manifest[..end].trim_end().trim_end_matches(','),
+        &manifest[end..]
+    )))
+}
+
+/// Adds a path dependency on `name` after the other days, keeping them in order.
+fn add_dependency(manifest: &str, name: &str) -> Result<Option<String>, String> {
+    let line = format!("{name} = {{ path = \"../{name}\" }}\n");
+    let mut lines = manifest.split_inclusive('\n').collect::<Vec<_>>();
+    if lines.iter().any(|l| l.starts_with(&format!("{name} "))) {
+        return Ok(None);
+    }
+    let section = lines
+        .iter()
+        .position(|l| l.trim() == "[dependencies]")
+        .ok_or("the runner manifest has no [dependencies]")?;
+    let position = lines[section + 1..]
+        .iter()
+        .position(|l| l.starts_with('[') || l.trim().is_empty() || l.as_bytes() > line.as_bytes())
+        .map_or(lines.len(), |offset| section + 1 + offset);
+    lines.insert(position, &line);
+    Ok(Some(lines.concat()))
+}
+
+/// Adds `DayNN` at the end of the `days!` invocation of the runner.
+fn add_day(registry: &str, day: u8) -> Result<Option<String>, String> {
+    let entry = format!("day_{day:02}::Day{day:02},");
+    if registry.contains(&entry) {
+        return Ok(None);
+    }
+    let start = registry
+        .find("days![")
+        .ok_or("the runner registers no days")?;
+    let end = start + registry[start..].find("];").unwrap();
+    Ok(Some(format!(
+        "{}    {entry}\n{}",
+        &registry[..end],
+        &registry[end..]
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn new_day_test() {
+        let workspace = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        fs::write(
+            workspace.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"day-01\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            workspace.join("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\nday-01 = { path = \"../day-01\" }\nserde = \"1\"\n",
+        )
+        .unwrap();
+        fs::write(
+            workspace.join("aoc/src/days.rs"),
+            "days![\n    day_01::Day01,\n];\n",
+        )
+        .unwrap();
+
+        assert_eq!(new_day(&workspace, 2).unwrap().len(), 8);
+        assert_eq!(
+            fs::read_to_string(workspace.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"day-01\", \"day-02\"]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\nclap = \"4\"\nday-01 = { path = \"../day-01\" }\n\
+             day-02 = { path = \"../day-02\" }\nserde = \"1\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(workspace.join("aoc/src/days.rs")).unwrap(),
+            "days![\n    day_01::Day01,\n    day_02::Day02,\n];\n"
+        );
+        let lib = fs::read_to_string(workspace.join("day-02/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day02;\n"));
+        assert!(lib.contains("const DAY: u8 = 2;\n"));
+        let gen = fs::read_to_string(workspace.join("day-02/src/gen.rs")).unwrap();
+        assert!(gen.contains("assert_generates::<Day02>"));
+
+        assert!(new_day(&workspace, 2).is_err());
+
+        // a day fetched before it was scaffolded keeps its input
+        fs::create_dir_all(workspace.join("day-03")).unwrap();
+        fs::write(workspace.join("day-03/input.txt"), "1\n").unwrap();
+        assert_eq!(new_day(&workspace, 3).unwrap().len(), 8);
+        assert_eq!(
+            fs::read_to_string(workspace.join("day-03/input.txt")).unwrap(),
+            "1\n"
+        );
+    }
+
+    #[test]
+    fn glob_member_test() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"day-*\"]\n";
+        assert_eq!(add_member(manifest, "day-25").unwrap(), None);
+    }
+}
@@ -0,0 +1,273 @@
+//! Solves puzzle inputs posted over HTTP, for tools that can't link the solutions.
+//!
+//! `POST /days/{day}/parts/{part}` takes the puzzle input as its body and answers with the JSON
+//! of an [`AnswerRecord`]. Failures answer with a JSON object holding an `error` message.
+
+use crate::{
+    jobs::{self, Outcome, Slot, Slots},
+    record::AnswerRecord,
+};
+use aoc_common::{solution::Runner, Part};
+use serde_json::json;
+use std::{io::Read, net::SocketAddr, sync::Arc, thread, time::Duration};
+use tiny_http::{Header, Method, Request, Response};
+
+pub const DEFAULT_ADDR: &str = "127.0.0.1:8022";
+
+/// Largest input accepted by default, in bytes, a few times the size of the largest real input.
+pub const DEFAULT_MAX_INPUT: usize = 1 << 20;
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Failed request, with its HTTP status.
+type Rejection = (u16, String);
+
+/// HTTP server answering with the solutions of a registry of days, see [`Server::run`].
+pub struct Server {
+    http: tiny_http::Server,
+    days: &'static [(u8, Runner)],
+    max_input: usize,
+    timeout: Duration,
+    solving: Arc<Slots>,
+}
+impl Server {
+    /// Listens on `addr`, e.g. `127.0.0.1:0` for any free port, to solve the given `days`.
+    pub fn bind(addr: &str, days: &'static [(u8, Runner)]) -> Result<Self, String> {
+        let http = tiny_http::Server::http(addr).map_err(|err| format!("{addr}: {err}"))?;
+        Ok(Self {
+            http,
+            days,
+            max_input: DEFAULT_MAX_INPUT,
+            timeout: DEFAULT_TIMEOUT,
+            solving: Slots::new(thread::available_parallelism().map_or(1, |n| n.get())),
+        })
+    }
+
+    /// Rejects inputs longer than `max_input` bytes.
+    pub fn with_max_input(self, max_input: usize) -> Self {
+        Self { max_input, ..self }
+    }
+
+    /// Gives up on requests whose part takes longer than `timeout` to solve.
+    ///
+    /// Solutions can't be interrupted, so one that times out still keeps a thread busy until it
+    /// finishes.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /// Answers at most `max_solves` requests at a time, the number of CPUs by default, and
+    /// turns the others away with `503 Service Unavailable`.
+    ///
+    /// Parts that timed out count until they finish.
+    pub fn with_max_solves(self, max_solves: usize) -> Self {
+        Self {
+            solving: Slots::new(max_solves),
+            ..self
+        }
+    }
+
+    pub fn addr(&self) -> Option<SocketAddr> {
+        self.http.server_addr().to_ip()
+    }
+
+    /// Answers requests until the process exits, each on its own thread so that a slow part
+    /// doesn't hold up the others.
+    pub fn run(self) {
+        let server = Arc::new(self);
+        for request in server.http.incoming_requests() {
+            let Some(slot) = server.solving.try_acquire() else {
+                server.respond(
+                    request,
+                    Err((503, "too many requests, try again later".into())),
+                );
+                continue;
+            };
+            let server = Arc::clone(&server);
+            thread::spawn(move || server.handle(request, slot));
+        }
+    }
+
+    fn handle(&self, mut request: Request, slot: Slot) {
+        let result = self.solve(&mut request, slot);
+        self.respond(request, result);
+    }
+
+    fn respond(&self, request: Request, result: Result<AnswerRecord, Rejection>) {
+        let (status, body) = match result {
+            Ok(record) => (200, serde_json::to_string(&record).unwrap()),
+            Err((status, error)) => (status, json!({ "error": error }).to_string()),
+        };
+        tracing::info!(method = %request.method(), url = request.url(), status);
+        let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type);
+        // the client may have given up waiting, there is no one left to tell
+        let _ = request.respond(response);
+    }
+
+    fn solve(&self, request: &mut Request, slot: Slot) -> Result<AnswerRecord, Rejection> {
+        let Some((day, part)) = route(request.url()) else {
+            return Err((404, "expected POST /days/{day}/parts/{part}".into()));
+        };
+        if *request.method() != Method::Post {
+            return Err((405, format!("expected POST, not {}", request.method())));
+        }
+        let runner = self
+            .days
+            .iter()
+            .find_map(|&(d, runner)| (d == day).then_some(runner))
+            .ok_or_else(|| (404, format!("day {day} is not solved yet")))?;
+        let part = Part::try_from(part).map_err(|err| (404, err))?;
+        let input = self.read_input(request)?;
+
+        match jobs::run_part(runner, &input, part, self.timeout, slot) {
+            Outcome::Solved(report) => Ok(AnswerRecord::from_report(&report, &input).remove(0)),
+            Outcome::Invalid(err) => Err((422, err.report("input", &input))),
+            Outcome::TimedOut => Err((
+                504,
+                format!("gave up after {} s", self.timeout.as_secs_f64()),
+            )),
+            Outcome::Panicked => Err((500, "the solution panicked, see the server logs".into())),
+        }
+    }
+
+    /// Reads the body, without ever holding more than `max_input` bytes of it.
+    fn read_input(&self, request: &mut Request) -> Result<Arc<str>, Rejection> {
+        let too_large = || {
+            (
+                413,
+                format!("inputs are limited to {} bytes", self.max_input),
+            )
+        };
+        if request
+            .body_length()
+            .is_some_and(|len| len > self.max_input)
+        {
+            return Err(too_large());
+        }
+        let mut input = String::new();
+        request
+            .as_reader()
+            .take(self.max_input as u64 + 1)
+            .read_to_string(&mut input)
+            .map_err(|err| (400, format!("cannot read the input: {err}")))?;
+        if input.len() > self.max_input {
+            return Err(too_large());
+        }
+        Ok(input.into())
+    }
+}
+
+/// Day and part of `/days/{day}/parts/{part}`.
+fn route(url: &str) -> Option<(u8, u8)> {
+    let path = url.split('?').next()?;
+    let (day, part) = path.strip_prefix("/days/")?.split_once("/parts/")?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solution::DayReport, ParseError, Solution};
+    use serde_json::Value;
+
+    fn sleep(_: &str, _: &[Part]) -> Result<DayReport, ParseError> {
+        thread::sleep(Duration::from_secs(5));
+        unreachable!("the request timed out long ago")
+    }
+
+    const DAYS: &[(u8, Runner)] = &[(1, day_01::Day01::run), (2, sleep)];
+
+    /// Serves `DAYS` on a free port for the rest of the test run.
+    fn start(server: Server) -> String {
+        let url = format!("http://{}", server.addr().unwrap());
+        thread::spawn(move || server.run());
+        url
+    }
+
+    fn post(url: &str, body: &str) -> (u16, Value) {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .new_agent();
+        let mut response = agent.post(url).send(body).unwrap();
+        let body = response.body_mut().read_to_string().unwrap();
+        (
+            response.status().as_u16(),
+            serde_json::from_str(&body).unwrap(),
+        )
+    }
+
+    #[test]
+    fn route_test() {
+        assert_eq!(route("/days/7/parts/2"), Some((7, 2)));
+        assert_eq!(route("/days/7/parts/2?verbose"), Some((7, 2)));
+        assert_eq!(route("/days/7"), None);
+        assert_eq!(route("/days/x/parts/1"), None);
+    }
+
+    #[test]
+    fn answer_test() {
+        let url = start(Server::bind("127.0.0.1:0", DAYS).unwrap());
+        let (status, body) = post(&format!("{url}/days/1/parts/2"), "1\n2\n\n4\n\n5\n");
+        assert_eq!(status, 200);
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["part"], 2);
+        assert_eq!(body["answer"], "12");
+        assert!(body["duration_ns"].is_u64());
+    }
+
+    #[test]
+    fn rejected_test() {
+        let url = start(
+            Server::bind("127.0.0.1:0", DAYS)
+                .unwrap()
+                .with_max_input(16),
+        );
+        let status = |path: &str, body: &str| post(&format!("{url}{path}"), body).0;
+        assert_eq!(status("/days/3/parts/1", "1\n"), 404);
+        assert_eq!(status("/days/1/parts/3", "1\n"), 404);
+        assert_eq!(status("/answers", "1\n"), 404);
+        assert_eq!(status("/days/1/parts/1", &"1\n".repeat(9)), 413);
+
+        let (status, body) = post(&format!("{url}/days/1/parts/1"), "1\nx\n");
+        assert_eq!(status, 422);
+        assert!(body["error"].as_str().unwrap().contains("x"));
+
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .new_agent();
+        let response = agent.get(format!("{url}/days/1/parts/1")).call().unwrap();
+        assert_eq!(response.status().as_u16(), 405);
+    }
+
+    #[test]
+    fn timeout_test() {
+        let url = start(
+            Server::bind("127.0.0.1:0", DAYS)
+                .unwrap()
+                .with_timeout(Duration::from_millis(50)),
+        );
+        let (status, body) = post(&format!("{url}/days/2/parts/1"), "");
+        assert_eq!(status, 504);
+        assert_eq!(body["error"], "gave up after 0.05 s");
+    }
+
+    #[test]
+    fn busy_test() {
+        let url = start(
+            Server::bind("127.0.0.1:0", DAYS)
+                .unwrap()
+                .with_timeout(Duration::from_millis(50))
+                .with_max_solves(1),
+        );
+        assert_eq!(post(&format!("{url}/days/2/parts/1"), "").0, 504);
+        // the part that timed out is still being solved
+        let (status, body) = post(&format!("{url}/days/1/parts/1"), "1\n");
+        assert_eq!(status, 503);
+        assert_eq!(body["error"], "too many requests, try again later");
+    }
+}
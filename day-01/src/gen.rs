@@ -0,0 +1,29 @@
+//! `size` elves, each carrying 1 to 15 snacks.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let elves = (0..size)
+        .map(|_| {
+            let snacks = (0..rng.gen_range(1..=15))
+                .map(|_| rng.gen_range(1000..=60000).to_string())
+                .collect::<Vec<_>>();
+            snacks.join("\n") + "\n"
+        })
+        .collect::<Vec<_>>();
+    elves.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day01>(generate, 100);
+    }
+}
@@ -0,0 +1,200 @@
+pub mod gen;
+pub mod stream;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+use std::{cmp::Reverse, fmt::Display};
+
+pub struct Day01;
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("24000")
+        .part2("45000")];
+
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(inventory: &Self::Input) -> impl Display {
+        inventory.rank(1).total()
+    }
+
+    fn part2(inventory: &Self::Input) -> impl Display {
+        inventory.rank(3).total()
+    }
+}
+
+/// An elf and the calories of every snack it carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the input, from 0.
+    pub index: usize,
+    pub items: Vec<u64>,
+    pub total: u64,
+}
+
+/// Every elf of the expedition, in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+impl Inventory {
+    /// The `n` elves carrying the most calories, and those tied with the last of them.
+    pub fn rank(&self, n: usize) -> Ranking<'_> {
+        let mut sorted = self.elves.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|elf| (Reverse(elf.total), elf.index));
+        let tied = match n.checked_sub(1).and_then(|last| sorted.get(last)) {
+            Some(last) => sorted[n..]
+                .iter()
+                .take_while(|elf| elf.total == last.total)
+                .copied()
+                .collect(),
+            None => Vec::new(),
+        };
+        sorted.truncate(n);
+        Ranking { top: sorted, tied }
+    }
+
+    /// Statistics of the calories carried per elf, `None` without elves.
+    pub fn stats(&self) -> Option<Stats> {
+        if self.elves.is_empty() {
+            return None;
+        }
+        let mut totals = self.elves.iter().map(|elf| elf.total).collect::<Vec<_>>();
+        totals.sort_unstable();
+        Some(Stats { totals })
+    }
+}
+
+/// Elves carrying the most calories, see [`Inventory::rank`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking<'a> {
+    /// Most calories first, elves carrying as much in input order.
+    pub top: Vec<&'a Elf>,
+    /// Elves left out of `top` that carry as much as its last elf.
+    pub tied: Vec<&'a Elf>,
+}
+impl Ranking<'_> {
+    pub fn total(&self) -> u64 {
+        self.top.iter().map(|elf| elf.total).sum()
+    }
+
+    pub fn indices(&self) -> Vec<usize> {
+        self.top.iter().map(|elf| elf.index).collect()
+    }
+}
+
+/// Calories carried per elf, see [`Inventory::stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// Never empty, in increasing order.
+    totals: Vec<u64>,
+}
+impl Stats {
+    pub fn min(&self) -> u64 {
+        self.totals[0]
+    }
+
+    pub fn max(&self) -> u64 {
+        self.totals[self.totals.len() - 1]
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.totals.iter().sum::<u64>() as f64 / self.totals.len() as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        self.percentile(50.0)
+    }
+
+    /// Calories that `p` percent of the elves carry at most, interpolated between the two elves
+    /// closest to that rank.
+    pub fn percentile(&self, p: f64) -> f64 {
+        assert!((0.0..=100.0).contains(&p), "{p} is not a percentage");
+        let rank = p / 100.0 * (self.totals.len() - 1) as f64;
+        let below = self.totals[rank.floor() as usize] as f64;
+        let above = self.totals[rank.ceil() as usize] as f64;
+        below + (above - below) * rank.fract()
+    }
+}
+
+fn parse(input: &str) -> Result<Inventory, ParseError> {
+    let source = Source::new(input);
+    let elves = input
+        .split_terminator("\n\n")
+        .enumerate()
+        .map(|(index, elf)| {
+            let items = elf
+                .lines()
+                .map(|line| source.number(line))
+                .collect::<Result<Vec<u64>, _>>()?;
+            Ok(Elf {
+                index,
+                total: items.iter().sum(),
+                items,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok(Inventory { elves })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, Day01};
+    use aoc_common::{example::assert_examples, fixture, Solution};
+
+    #[test]
+    fn part1_test() {
+        let inventory = parse(fixture!("test-input")).unwrap();
+        assert_eq!(24000, inventory.rank(1).total());
+    }
+
+    #[test]
+    fn part2_test() {
+        let inventory = parse(fixture!("test-input")).unwrap();
+        assert_eq!(45000, inventory.rank(3).total());
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day01>();
+    }
+
+    #[test]
+    fn solve_test() {
+        let answers = Day01::solve("1\n2\n\n4\n\n2").unwrap();
+        assert_eq!(answers, ["4", "9"]);
+    }
+
+    #[test]
+    fn ties_test() {
+        let answers = Day01::solve("1\n2\n\n3\n\n1\n1\n1\n\n2\n").unwrap();
+        assert_eq!(answers, ["3", "9"]);
+    }
+
+    #[test]
+    fn rank_test() {
+        let inventory = parse("1\n2\n\n3\n\n1\n1\n1\n\n2\n\n4\n").unwrap();
+        let ranking = inventory.rank(2);
+        assert_eq!(ranking.indices(), [4, 0]);
+        assert_eq!(ranking.top[1].items, [1, 2]);
+        let tied = ranking.tied.iter().map(|elf| elf.index).collect::<Vec<_>>();
+        assert_eq!(tied, [1, 2]);
+        assert!(inventory.rank(5).tied.is_empty());
+        assert_eq!(inventory.rank(9).top.len(), 5);
+        assert_eq!(inventory.rank(0).total(), 0);
+    }
+
+    #[test]
+    fn stats_test() {
+        let stats = parse(fixture!("test-input")).unwrap().stats().unwrap();
+        assert_eq!((stats.min(), stats.max()), (4000, 24000));
+        assert_eq!(stats.mean(), 11000.0);
+        assert_eq!(stats.median(), 10000.0);
+        assert_eq!(stats.percentile(25.0), 6000.0);
+        assert_eq!(stats.percentile(90.0), 18800.0);
+        assert_eq!(parse("").unwrap().stats(), None);
+    }
+}
@@ -1,36 +1,43 @@
-use std::{collections::BTreeSet, fs::read_to_string};
+use aoc_common::{
+    solution::{print_solutions, STDIN},
+    Part,
+};
+use day_01::{stream::top_elves, Day01};
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    process::ExitCode,
+};
 
-fn main() {
-    let sums = parse("input.txt");
-    println!("part1 solution: {:?}", get_top_n_sum(&sums, 1));
-    println!("part2 solution: {}", get_top_n_sum(&sums, 3));
-}
-
-fn get_top_n_sum(sums: &BTreeSet<isize>, n: usize) -> isize {
-    sums.iter().rev().take(n).sum()
-}
-
-fn parse(filename: &str) -> BTreeSet<isize> {
-    read_to_string(filename)
-        .expect("failed to read file")
-        .split_terminator("\n\n")
-        .map(|elf| elf.lines().map(|line| line.parse::<isize>().unwrap()).sum())
-        .collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{get_top_n_sum, parse};
-
-    #[test]
-    fn part1_test() {
-        let sums = parse("test-input.txt");
-        assert_eq!(24000, get_top_n_sum(&sums, 1));
+/// `--stream` ranks the elves while reading the input, for inventories too large to hold in
+/// memory.
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if !args.iter().any(|arg| arg == "--stream") {
+        return print_solutions::<Day01>("input.txt");
     }
+    let filename = args
+        .into_iter()
+        .find(|arg| !matches!(arg.as_str(), "--stream" | "--verbose" | "-v"))
+        .unwrap_or_else(|| "input.txt".into());
+    let top = match open(&filename).and_then(|reader| top_elves(reader, 3)) {
+        Ok(top) => top,
+        Err(err) => {
+            eprintln!("error: {filename}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let total = |n| top.iter().take(n).map(|&(_, total)| total).sum::<u64>();
+    println!("{} solution {}", Part::One, total(1));
+    println!("{} solution {}", Part::Two, total(3));
+    ExitCode::SUCCESS
+}
 
-    #[test]
-    fn part2_test() {
-        let sums = parse("test-input.txt");
-        assert_eq!(45000, get_top_n_sum(&sums, 3));
-    }
+fn open(filename: &str) -> io::Result<Box<dyn BufRead>> {
+    Ok(if filename == STDIN {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(BufReader::new(File::open(filename)?))
+    })
 }
@@ -0,0 +1,96 @@
+//! Ranks the elves while reading the inventory, for inventories too large to hold in memory.
+
+use crate::Day01;
+use aoc_common::{ParseError, Solution};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{self, BufRead},
+};
+
+/// Index and total of the `n` elves carrying the most calories in the inventory read from
+/// `reader`, most first and ties broken by index like [`crate::Inventory::rank`].
+///
+/// Only the current line and the `n` best elves so far are kept, whatever the size of the
+/// inventory. Malformed lines fail with an [`io::ErrorKind::InvalidData`] error wrapping a
+/// [`ParseError`].
+pub fn top_elves(mut reader: impl BufRead, n: usize) -> io::Result<Vec<(usize, u64)>> {
+    // the worst of the best elves on top, to be pushed out by a better one
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+    let mut push = |index: usize, total: u64| {
+        heap.push(Reverse((total, Reverse(index))));
+        if heap.len() > n {
+            heap.pop();
+        }
+    };
+
+    let (mut line, mut number, mut index) = (String::new(), 0, 0);
+    let mut current = None;
+    while reader.read_line(&mut line)? > 0 {
+        number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+            if let Some(total) = current.take() {
+                push(index, total);
+                index += 1;
+            }
+        } else {
+            let calories = text.parse::<u64>().map_err(|err| {
+                let err = ParseError::new(number, 1, text, format!("invalid number: {err}"));
+                io::Error::new(io::ErrorKind::InvalidData, err.with_day(Day01::DAY))
+            })?;
+            current = Some(current.unwrap_or(0) + calories);
+        }
+        line.clear();
+    }
+    if let Some(total) = current {
+        push(index, total);
+    }
+
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| (index, total))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gen::generate, parse};
+    use aoc_common::{fixture, gen::rng};
+
+    #[test]
+    fn top_elves_test() {
+        let inventory = fixture!("test-input");
+        let top = top_elves(inventory.as_bytes(), 3).unwrap();
+        assert_eq!(top, [(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(top_elves(inventory.as_bytes(), 0).unwrap(), []);
+        assert_eq!(top_elves(&b""[..], 3).unwrap(), []);
+        let tied = top_elves(&b"5\n\n5\n\n5\n"[..], 2).unwrap();
+        assert_eq!(tied, [(0, 5), (1, 5)]);
+    }
+
+    #[test]
+    fn same_as_rank_test() {
+        let input = generate(&mut rng(1), 200) + "\n1000\n\n1000\n";
+        let inventory = parse(&input).unwrap();
+        for n in [1, 3, 10, 250] {
+            let ranked = inventory
+                .rank(n)
+                .top
+                .iter()
+                .map(|elf| (elf.index, elf.total))
+                .collect::<Vec<_>>();
+            assert_eq!(top_elves(input.as_bytes(), n).unwrap(), ranked);
+        }
+    }
+
+    #[test]
+    fn invalid_test() {
+        let err = top_elves(&b"1\n\n2x\n"[..], 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = err.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((err.day, err.line, err.text.as_str()), (1, 3, "2x"));
+    }
+}
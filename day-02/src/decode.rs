@@ -0,0 +1,181 @@
+//! Scores every way of reading the second column of the guide, to tell what it most likely
+//! meant, see [`search`].
+
+use crate::{Guide, Interpretation, Outcome, Play, Round, Rules};
+use itertools::Itertools;
+use std::cmp::Reverse;
+
+/// What each response letter stands for, indexed like [`Rules::responses`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decoding {
+    Moves(Vec<usize>),
+    Outcomes(Vec<Outcome>),
+}
+impl Decoding {
+    /// The reading given by the rules themselves, `None` if some response has no meaning in it.
+    pub fn of(rules: &Rules, interpretation: Interpretation) -> Option<Self> {
+        let responses = rules.responses().iter();
+        Some(match interpretation {
+            Interpretation::Move => Self::Moves(responses.map(|r| r.play).collect()),
+            Interpretation::Outcome => {
+                Self::Outcomes(responses.map(|r| r.outcome).collect::<Option<_>>()?)
+            }
+        })
+    }
+
+    pub fn play(&self, rules: &Rules, round: Round) -> Play {
+        let chosen = match self {
+            Self::Moves(moves) => moves[round.response],
+            Self::Outcomes(outcomes) => rules.choose(round.opponent, outcomes[round.response]),
+        };
+        Play {
+            opponent: round.opponent,
+            chosen,
+            outcome: rules.outcome(chosen, round.opponent),
+        }
+    }
+
+    /// Each letter and what it stands for, e.g. `X=rock Y=paper Z=scissors`.
+    pub fn describe(&self, rules: &Rules) -> String {
+        let meanings: Vec<String> = match self {
+            Self::Moves(moves) => moves
+                .iter()
+                .map(|&m| rules.moves()[m].name.clone())
+                .collect(),
+            Self::Outcomes(outcomes) => outcomes.iter().map(Outcome::to_string).collect(),
+        };
+        rules
+            .responses()
+            .iter()
+            .zip(meanings)
+            .map(|(response, meaning)| format!("{}={meaning}", response.letter))
+            .join(" ")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scored {
+    pub decoding: Decoding,
+    pub total: usize,
+}
+
+/// Most readings [`search`] tries, a million take about two seconds.
+pub const MAX_READINGS: usize = 1_000_000;
+
+/// Every reading of the responses as distinct moves, or as distinct outcomes, with the total it
+/// scores over `guide`, best first.
+///
+/// Empty when there are more responses than moves or outcomes to read them as, and an error
+/// when there are more than [`MAX_READINGS`] of them.
+pub fn search(guide: &Guide, interpretation: Interpretation) -> Result<Vec<Scored>, String> {
+    let rules = &guide.rules;
+    // rounds only differ by their letters, score each pair of letters once
+    let mut counts = vec![vec![0; rules.responses().len()]; rules.moves().len()];
+    for round in &guide.rounds {
+        counts[round.opponent][round.response] += 1;
+    }
+    let meanings = match interpretation {
+        Interpretation::Move => rules.moves().len(),
+        Interpretation::Outcome => Outcome::ALL.len(),
+    };
+    let responses = rules.responses().len();
+    let readings = (meanings.saturating_sub(responses) + 1..=meanings)
+        .try_fold(1, usize::checked_mul)
+        .filter(|&readings| readings <= MAX_READINGS || responses > meanings);
+    if readings.is_none() {
+        return Err(format!(
+            "{responses} responses have more than {MAX_READINGS} readings as {interpretation}s"
+        ));
+    }
+    let mut scored = (0..meanings)
+        .permutations(responses)
+        .map(|meanings| {
+            let decoding = match interpretation {
+                Interpretation::Move => Decoding::Moves(meanings),
+                Interpretation::Outcome => {
+                    Decoding::Outcomes(meanings.into_iter().map(|o| Outcome::ALL[o]).collect())
+                }
+            };
+            let total = counts
+                .iter()
+                .enumerate()
+                .flat_map(|(opponent, counts)| {
+                    counts
+                        .iter()
+                        .enumerate()
+                        .map(move |(response, &count)| (Round { opponent, response }, count))
+                })
+                .map(|(round, count)| count * rules.score(&decoding.play(rules, round)))
+                .sum();
+            Scored { decoding, total }
+        })
+        .collect::<Vec<_>>();
+    scored.sort_by_key(|scored| Reverse(scored.total));
+    Ok(scored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::{fixture, Solution};
+
+    #[test]
+    fn moves_test() {
+        let guide = Day02::parse(fixture!("test-input")).unwrap();
+        let scored = search(&guide, Interpretation::Move).unwrap();
+        assert_eq!(scored.len(), 6);
+        assert_eq!(scored[0].decoding, Decoding::Moves(vec![2, 1, 0]));
+        assert_eq!(scored[0].total, 24);
+        assert_eq!(scored[5].decoding, Decoding::Moves(vec![0, 2, 1]));
+        assert_eq!(scored[5].total, 6);
+
+        let puzzle = Decoding::of(&guide.rules, Interpretation::Move).unwrap();
+        let total = scored.iter().find(|s| s.decoding == puzzle).unwrap().total;
+        assert_eq!(Some(total), guide.total(Interpretation::Move));
+    }
+
+    #[test]
+    fn outcomes_test() {
+        let guide = Day02::parse(fixture!("test-input")).unwrap();
+        let scored = search(&guide, Interpretation::Outcome).unwrap();
+        assert_eq!(scored.len(), 6);
+        let best = &scored[0];
+        assert_eq!(best.total, 18);
+        assert_eq!(best.decoding.describe(&guide.rules), "X=win Y=lose Z=draw");
+        let worst = &scored[5];
+        assert_eq!(worst.total, 12);
+        assert_eq!(
+            Some(&worst.decoding),
+            Decoding::of(&guide.rules, Interpretation::Outcome).as_ref()
+        );
+    }
+
+    #[test]
+    fn too_many_responses_test() {
+        let config = include_str!("../rules/rock-paper-scissors-lizard-spock.toml");
+        let guide = Guide::parse("A V\n", Rules::from_toml(config).unwrap()).unwrap();
+        assert_eq!(search(&guide, Interpretation::Move).unwrap().len(), 120);
+        assert!(search(&guide, Interpretation::Outcome).unwrap().is_empty());
+        assert_eq!(Decoding::of(&guide.rules, Interpretation::Outcome), None);
+    }
+
+    #[test]
+    fn too_many_readings_test() {
+        // 11 moves, each beating the 5 after it, and a response for each
+        let mut config = String::from("[outcomes]\nlose = 0\ndraw = 3\nwin = 6\n");
+        for m in 0..11 {
+            let beats = (1..=5).map(|n| format!("\"m{}\"", (m + n) % 11)).join(", ");
+            config += &format!(
+                "[[moves]]\nname = \"m{m}\"\nletter = \"A{m}\"\nscore = {m}\nbeats = [{beats}]\n"
+            );
+            config += &format!("[[responses]]\nletter = \"X{m}\"\nplay = \"m{m}\"\n");
+        }
+        let guide = Guide::parse("A0 X0\n", Rules::from_toml(&config).unwrap()).unwrap();
+        assert_eq!(
+            search(&guide, Interpretation::Move).unwrap_err(),
+            "11 responses have more than 1000000 readings as moves"
+        );
+        assert!(search(&guide, Interpretation::Outcome).unwrap().is_empty());
+    }
+}
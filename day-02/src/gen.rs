@@ -0,0 +1,27 @@
+//! `size` rounds of rock paper scissors.
+
+use aoc_common::gen::{SliceRandom, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let opponent = ["A", "B", "C"].choose(rng).unwrap();
+            let response = ["X", "Y", "Z"].choose(rng).unwrap();
+            format!("{opponent} {response}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day02>(generate, 100);
+    }
+}
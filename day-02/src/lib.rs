@@ -0,0 +1,162 @@
+pub mod decode;
+pub mod gen;
+pub mod report;
+pub mod rules;
+
+pub use rules::{Outcome, Rules};
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+
+use serde::Serialize;
+use std::fmt::{self, Display};
+
+pub struct Day02;
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("15")
+        .part2("12")];
+
+    type Input = Guide;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Guide::parse(input, Rules::default())
+    }
+
+    fn part1(guide: &Self::Input) -> impl Display {
+        guide
+            .total(Interpretation::Move)
+            .expect("every response of the puzzle is a move")
+    }
+
+    fn part2(guide: &Self::Input) -> impl Display {
+        guide
+            .total(Interpretation::Outcome)
+            .expect("every response of the puzzle is an outcome")
+    }
+}
+
+/// What the second column of the guide tells: the move to play in part 1, the outcome to get in
+/// part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Interpretation {
+    Move,
+    Outcome,
+}
+impl Display for Interpretation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Move => "move",
+            Self::Outcome => "outcome",
+        })
+    }
+}
+
+/// One line of the guide, as indices in the moves and the responses of its [`Rules`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub response: usize,
+}
+
+/// A round once its response is read, see [`Rules::play`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub opponent: usize,
+    pub chosen: usize,
+    pub outcome: Outcome,
+}
+
+/// Strategy guide, with the rules it was written for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guide {
+    pub rules: Rules,
+    pub rounds: Vec<Round>,
+}
+impl Guide {
+    pub fn parse(input: &str, rules: Rules) -> Result<Self, ParseError> {
+        let source = Source::new(input);
+        let moves = rules.moves().iter().map(|m| m.letter.as_str()).collect();
+        let responses = rules
+            .responses()
+            .iter()
+            .map(|r| r.letter.as_str())
+            .collect();
+        let letter = |text: &str, letters: &Vec<&str>| {
+            letters
+                .iter()
+                .position(|&letter| letter == text)
+                .ok_or_else(|| {
+                    source.error(text, format!("expected one of {}", letters.join(", ")))
+                })
+        };
+        let rounds = input
+            .lines()
+            .map(|l| {
+                let (opponent, response) = source.split_once(l, " ")?;
+                Ok(Round {
+                    opponent: letter(opponent, &moves)?,
+                    response: letter(response, &responses)?,
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { rules, rounds })
+    }
+
+    /// Every round read with `interpretation`, `None` if a response doesn't stand for anything in
+    /// it.
+    pub fn plays(&self, interpretation: Interpretation) -> Option<Vec<Play>> {
+        self.rounds
+            .iter()
+            .map(|&round| self.rules.play(round, interpretation))
+            .collect()
+    }
+
+    pub fn total(&self, interpretation: Interpretation) -> Option<usize> {
+        let plays = self.plays(interpretation)?;
+        Some(plays.iter().map(|play| self.rules.score(play)).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day02, Guide, Interpretation, Outcome, Play, Rules};
+    use aoc_common::{example::assert_examples, fixture, Solution};
+
+    #[test]
+    fn part1_test() {
+        let guide = Day02::parse(fixture!("test-input")).unwrap();
+        assert_eq!(guide.total(Interpretation::Move), Some(15));
+    }
+
+    #[test]
+    fn part2_test() {
+        let guide = Day02::parse(fixture!("test-input")).unwrap();
+        assert_eq!(guide.total(Interpretation::Outcome), Some(12));
+    }
+
+    #[test]
+    fn plays_test() {
+        let guide = Day02::parse(fixture!("test-input")).unwrap();
+        let plays = guide.plays(Interpretation::Outcome).unwrap();
+        let play = Play {
+            opponent: 0,
+            chosen: 0,
+            outcome: Outcome::Draw,
+        };
+        assert_eq!(plays[0], play);
+        assert_eq!(guide.rules.score(&play), 4);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = Guide::parse("A Y\nB Q\n", Rules::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "Q"));
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day02>();
+    }
+}
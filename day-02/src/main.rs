@@ -1,145 +1,197 @@
-use std::fs::read_to_string;
+use aoc_common::{
+    solution::{print_solutions, read_input},
+    Part, Solution,
+};
+use day_02::{
+    decode::{self, Decoding},
+    report::{self, Report},
+    Day02, Guide, Interpretation, Rules,
+};
+use std::{env, fs, path::Path, process::ExitCode};
 
-fn main() {
-    let actions = parse("input.txt");
-    println!(
-        "part1 solution: {}",
-        actions
-            .iter()
-            .map(|(opp_action, my_action)| my_action.calculate_outcome(opp_action))
-            .sum::<usize>()
-    );
-    println!(
-        "part2 solution: {}",
-        actions
-            .iter()
-            .map(|(opp_action, my_action)| Outcome::from(my_action).calculate_outcome(opp_action))
-            .sum::<usize>()
-    );
-}
+const PARTS: [(Part, Interpretation); 2] = [
+    (Part::One, Interpretation::Move),
+    (Part::Two, Interpretation::Outcome),
+];
 
-#[derive(PartialEq, Eq)]
-enum Action {
-    Rock,
-    Paper,
-    Scissors,
-}
-impl From<&str> for Action {
-    fn from(action: &str) -> Self {
-        match action {
-            "A" | "X" => Action::Rock,
-            "B" | "Y" => Action::Paper,
-            "C" | "Z" => Action::Scissors,
-            _ => panic!("Invalid action"),
+/// `--rules rules.toml` scores the guide with other rules than the puzzle's, see `rules/`.
+/// `--decode` scores every reading of the second column instead, see [`decode::search`].
+/// `--report csv|json|text` accounts for every round instead, see [`report::report`].
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let decode = take_flag(&mut args, "--decode");
+    let (path, format) = match take_value(&mut args, "--rules")
+        .and_then(|path| Ok((path, take_value(&mut args, "--report")?)))
+    {
+        Ok(values) => values,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
         }
-    }
-}
-impl Action {
-    fn get_value(&self) -> usize {
-        match self {
-            Self::Rock => 1,
-            Self::Paper => 2,
-            Self::Scissors => 3,
+    };
+    if let Some(format) = format.as_deref() {
+        if !FORMATS.contains(&format) {
+            eprintln!(
+                "error: unknown report format {format}, expected one of {}",
+                FORMATS.join(", ")
+            );
+            return ExitCode::FAILURE;
         }
     }
+    if !decode && path.is_none() && format.is_none() {
+        return print_solutions::<Day02>("input.txt");
+    }
 
-    fn calculate_outcome(&self, action: &Action) -> usize {
-        self.get_value()
-            + match (action, self) {
-                (Action::Paper, Self::Rock)
-                | (Action::Scissors, Self::Paper)
-                | (Action::Rock, Self::Scissors) => 0,
-                (Action::Rock, Action::Rock)
-                | (Action::Paper, Action::Paper)
-                | (Action::Scissors, Action::Scissors) => 3,
-                (Action::Rock, Self::Paper)
-                | (Action::Paper, Self::Scissors)
-                | (Action::Scissors, Self::Rock) => 6,
+    let rules = match &path {
+        Some(path) => match fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|config| Rules::from_toml(&config))
+        {
+            Ok(rules) => rules,
+            Err(err) => {
+                eprintln!("error: {path}: {err}");
+                return ExitCode::FAILURE;
             }
-    }
-}
+        },
+        None => Rules::default(),
+    };
+    let path = path.as_deref().unwrap_or("the rules");
+    let filename = args
+        .into_iter()
+        .find(|arg| arg != "--verbose" && arg != "-v")
+        .unwrap_or_else(|| "input.txt".into());
+    let input = match read_input(Path::new(&filename)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: cannot read {filename}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let guide = match Guide::parse(&input, rules) {
+        Ok(guide) => guide,
+        Err(err) => {
+            eprintln!("{}", err.with_day(Day02::DAY).report(&filename, &input));
+            return ExitCode::FAILURE;
+        }
+    };
 
-#[derive(PartialEq, Eq)]
-enum Outcome {
-    Lose,
-    Draw,
-    Win,
-}
-impl From<&Action> for Outcome {
-    fn from(outcome: &Action) -> Self {
-        match outcome {
-            Action::Rock => Self::Lose,
-            Action::Paper => Self::Draw,
-            Action::Scissors => Self::Win,
+    if decode {
+        let mut code = ExitCode::SUCCESS;
+        for (part, interpretation) in PARTS {
+            if let Err(err) = print_decodings(&guide, part, interpretation) {
+                eprintln!("error: {part}: {err}");
+                code = ExitCode::FAILURE;
+            }
         }
+        return code;
     }
-}
-impl Outcome {
-    fn get_value(&self) -> usize {
-        match self {
-            Self::Lose => 0,
-            Self::Draw => 3,
-            Self::Win => 6,
+    let mut code = ExitCode::SUCCESS;
+    let mut reports = Vec::new();
+    for (part, interpretation) in PARTS {
+        match report::report(&guide, interpretation) {
+            Some(report) => reports.push((part, report)),
+            None => {
+                eprintln!("error: {part}: some responses have no outcome in {path}");
+                code = ExitCode::FAILURE;
+            }
         }
     }
-
-    fn calculate_outcome(&self, action: &Action) -> usize {
-        self.get_value()
-            + match (action, self) {
-                (a, Self::Draw) => a.get_value(),
-                (a, Self::Lose) => match a {
-                    Action::Rock => Action::Scissors,
-                    Action::Paper => Action::Rock,
-                    Action::Scissors => Action::Paper,
-                }
-                .get_value(),
-                (a, Self::Win) => match a {
-                    Action::Rock => Action::Paper,
-                    Action::Paper => Action::Scissors,
-                    Action::Scissors => Action::Rock,
-                }
-                .get_value(),
+    match format.as_deref() {
+        None => {
+            for (part, report) in &reports {
+                println!("{part} solution {}", report.total);
             }
+        }
+        Some("csv") => {
+            let reports = reports.into_iter().map(|(_, report)| report);
+            print!("{}", report::to_csv(&reports.collect::<Vec<_>>()));
+        }
+        Some("json") => {
+            let reports = reports.iter().map(|(_, report)| report).collect::<Vec<_>>();
+            println!("{}", serde_json::to_string_pretty(&reports).unwrap());
+        }
+        Some(_) => {
+            for (part, report) in &reports {
+                print_tallies(*part, report);
+            }
+        }
     }
+    code
 }
 
-fn parse(filename: &str) -> Vec<(Action, Action)> {
-    read_to_string(filename)
-        .expect("failed to read file")
-        .lines()
-        .map(|l| {
-            let (action1, action2) = l.split_once(' ').unwrap();
-            (action1.into(), action2.into())
-        })
-        .collect()
+const FORMATS: [&str; 3] = ["csv", "json", "text"];
+
+/// Removes `flag` and the value following it from `args`.
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(at) if at + 1 < args.len() => Ok(args.drain(at..=at + 1).nth(1)),
+        Some(_) => Err(format!("{flag} expects a value")),
+        None => Ok(None),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{parse, Outcome};
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() < len
+}
 
-    #[test]
-    fn part1_test() {
-        let actions = parse("test-input.txt");
-        assert_eq!(
-            actions
-                .iter()
-                .map(|(opp_action, my_action)| my_action.calculate_outcome(opp_action))
-                .sum::<usize>(),
-            15
-        )
+/// Best and worst readings of the guide for `part`, and where the reading of the rules ranks.
+fn print_decodings(
+    guide: &Guide,
+    part: Part,
+    interpretation: Interpretation,
+) -> Result<(), String> {
+    let scored = decode::search(guide, interpretation)?;
+    let (Some(best), Some(worst)) = (scored.first(), scored.last()) else {
+        println!("{part}: more responses than there are distinct meanings for them");
+        return Ok(());
+    };
+    println!("{part}: {} readings", scored.len());
+    let rules = &guide.rules;
+    println!(
+        "  best  {:>8}  {}",
+        best.total,
+        best.decoding.describe(rules)
+    );
+    println!(
+        "  worst {:>8}  {}",
+        worst.total,
+        worst.decoding.describe(rules)
+    );
+    let puzzle = Decoding::of(rules, interpretation);
+    if let Some(rank) = scored
+        .iter()
+        .position(|s| Some(&s.decoding) == puzzle.as_ref())
+    {
+        let own = &scored[rank];
+        println!(
+            "  rules {:>8}  {}, {} of {}",
+            own.total,
+            own.decoding.describe(rules),
+            rank + 1,
+            scored.len(),
+        );
     }
+    Ok(())
+}
 
-    #[test]
-    fn part2_test() {
-        let actions = parse("test-input.txt");
-        assert_eq!(
-            actions
-                .iter()
-                .map(|(opp_action, my_action)| Outcome::from(my_action)
-                    .calculate_outcome(opp_action))
-                .sum::<usize>(),
-            12
-        )
+/// Wins, draws and losses of every move, the rounds themselves being left to CSV and JSON.
+fn print_tallies(part: Part, report: &Report) {
+    println!(
+        "{part}: {} rounds read as {}s, total {}",
+        report.rounds.len(),
+        report.interpretation,
+        report.total
+    );
+    println!(
+        "  {:<10} {:>6} {:>6} {:>6}",
+        "move", "wins", "draws", "losses"
+    );
+    for tally in &report.moves {
+        println!(
+            "  {:<10} {:>6} {:>6} {:>6}",
+            tally.name, tally.wins, tally.draws, tally.losses
+        );
     }
 }
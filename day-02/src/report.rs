@@ -0,0 +1,185 @@
+//! Round by round account of a guide, with how often each move won, see [`report`].
+
+use crate::{Guide, Interpretation, Outcome};
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RoundReport {
+    /// 1-based, like the lines of the guide.
+    pub round: usize,
+    pub opponent: String,
+    pub chosen: String,
+    pub outcome: Outcome,
+    pub shape_points: usize,
+    pub outcome_points: usize,
+    pub running_total: usize,
+}
+
+/// How often a move won, drew and lost when chosen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Tally {
+    pub name: String,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub interpretation: Interpretation,
+    pub total: usize,
+    pub rounds: Vec<RoundReport>,
+    /// Every move of the rules, in their order, even those never chosen.
+    pub moves: Vec<Tally>,
+}
+
+pub const CSV_HEADER: &str =
+    "interpretation,round,opponent,chosen,outcome,shape_points,outcome_points,running_total";
+
+/// Plays `guide` with `interpretation`, `None` if a response doesn't stand for anything in it.
+pub fn report(guide: &Guide, interpretation: Interpretation) -> Option<Report> {
+    let rules = &guide.rules;
+    let name = |m: usize| rules.moves()[m].name.clone();
+    let mut moves = rules
+        .moves()
+        .iter()
+        .map(|m| Tally {
+            name: m.name.clone(),
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        })
+        .collect::<Vec<_>>();
+    let mut total = 0;
+    let rounds = guide
+        .plays(interpretation)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, play)| {
+            let tally = &mut moves[play.chosen];
+            *match play.outcome {
+                Outcome::Win => &mut tally.wins,
+                Outcome::Draw => &mut tally.draws,
+                Outcome::Lose => &mut tally.losses,
+            } += 1;
+            let shape_points = rules.moves()[play.chosen].score;
+            let outcome_points = rules.outcome_score(play.outcome);
+            total += shape_points + outcome_points;
+            RoundReport {
+                round: index + 1,
+                opponent: name(play.opponent),
+                chosen: name(play.chosen),
+                outcome: play.outcome,
+                shape_points,
+                outcome_points,
+                running_total: total,
+            }
+        })
+        .collect();
+    Some(Report {
+        interpretation,
+        total,
+        rounds,
+        moves,
+    })
+}
+
+/// The rounds of every report, one line each after [`CSV_HEADER`].
+pub fn to_csv(reports: &[Report]) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+    for report in reports {
+        for r in &report.rounds {
+            csv += &format!(
+                "{},{},{},{},{},{},{},{}\n",
+                report.interpretation,
+                r.round,
+                csv_field(&r.opponent),
+                csv_field(&r.chosen),
+                r.outcome,
+                r.shape_points,
+                r.outcome_points,
+                r.running_total
+            );
+        }
+    }
+    csv
+}
+
+/// Quotes `field` if it would otherwise break the line into more fields.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::{fixture, Solution};
+
+    #[test]
+    fn report_test() {
+        let guide = Day02::parse(fixture!("test-input")).unwrap();
+        let report = report(&guide, Interpretation::Move).unwrap();
+        assert_eq!(report.total, 15);
+        assert_eq!(
+            report.rounds[0],
+            RoundReport {
+                round: 1,
+                opponent: "rock".into(),
+                chosen: "paper".into(),
+                outcome: Outcome::Win,
+                shape_points: 2,
+                outcome_points: 6,
+                running_total: 8,
+            }
+        );
+        let totals = report
+            .rounds
+            .iter()
+            .map(|r| r.running_total)
+            .collect::<Vec<_>>();
+        assert_eq!(totals, [8, 9, 15]);
+        let tallies = report
+            .moves
+            .iter()
+            .map(|t| (t.name.as_str(), t.wins, t.draws, t.losses))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tallies,
+            [("rock", 0, 0, 1), ("paper", 1, 0, 0), ("scissors", 0, 1, 0)]
+        );
+
+        let report = super::report(&guide, Interpretation::Outcome).unwrap();
+        assert_eq!(report.total, 12);
+        assert_eq!(report.moves[0].draws, 1);
+    }
+
+    #[test]
+    fn csv_test() {
+        let guide = Day02::parse(fixture!("test-input")).unwrap();
+        let reports = [Interpretation::Move, Interpretation::Outcome]
+            .map(|interpretation| report(&guide, interpretation).unwrap());
+        let csv = to_csv(&reports);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "move,1,rock,paper,win,2,6,8");
+        assert_eq!(lines[6], "outcome,3,scissors,rock,win,1,6,12");
+        assert_eq!(csv_field("rock, obviously"), "\"rock, obviously\"");
+        assert_eq!(csv_field("\"spock\""), "\"\"\"spock\"\"\"");
+    }
+
+    #[test]
+    fn json_test() {
+        let guide = Day02::parse("A Y\n").unwrap();
+        let report = report(&guide, Interpretation::Outcome).unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["interpretation"], "outcome");
+        assert_eq!(json["rounds"][0]["outcome"], "draw");
+        assert_eq!(json["moves"][0]["draws"], 1);
+    }
+}
@@ -0,0 +1,291 @@
+//! Rules of rock paper scissors and its variants, loaded from TOML, see [`Rules::from_toml`].
+//!
+//! The `rules` directory of the crate has the rules of the puzzle and of rock paper scissors
+//! lizard Spock.
+
+use crate::{Interpretation, Play, Round};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// The rules of the puzzle.
+pub const DEFAULT: &str = include_str!("../rules/rock-paper-scissors.toml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+impl Outcome {
+    pub const ALL: [Self; 3] = [Self::Lose, Self::Draw, Self::Win];
+}
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Lose => "lose",
+            Self::Draw => "draw",
+            Self::Win => "win",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub name: String,
+    /// How the opponent's column of the guide writes the move.
+    pub letter: String,
+    pub score: usize,
+    /// Indices of the moves this one beats.
+    pub beats: Vec<usize>,
+}
+
+/// Letter of the second column of the guide and what it stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub letter: String,
+    /// Index of the move to play when the letter is read as a move.
+    pub play: usize,
+    /// Outcome to get when the letter is read as an outcome, if it stands for one.
+    pub outcome: Option<Outcome>,
+}
+
+/// Moves, which beats which, scores and letters of the guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    moves: Vec<Move>,
+    responses: Vec<Response>,
+    /// Indexed by [`Outcome`].
+    outcome_scores: [usize; 3],
+}
+impl Rules {
+    /// Reads and checks rules written like [`DEFAULT`].
+    pub fn from_toml(config: &str) -> Result<Self, String> {
+        let config = toml::from_str::<Config>(config).map_err(|err| err.to_string())?;
+        let find = |name: &str| {
+            config
+                .moves
+                .iter()
+                .position(|m| m.name == name)
+                .ok_or_else(|| format!("unknown move `{name}`"))
+        };
+        let moves = config
+            .moves
+            .iter()
+            .map(|m| {
+                Ok(Move {
+                    name: m.name.clone(),
+                    letter: m.letter.clone(),
+                    score: m.score,
+                    beats: m
+                        .beats
+                        .iter()
+                        .map(|name| find(name))
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let responses = config
+            .responses
+            .iter()
+            .map(|r| {
+                Ok(Response {
+                    letter: r.letter.clone(),
+                    play: find(&r.play)?,
+                    outcome: r.outcome,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let scores = config.outcomes;
+        let rules = Self {
+            moves,
+            responses,
+            outcome_scores: [scores.lose, scores.draw, scores.win],
+        };
+        rules.check()?;
+        Ok(rules)
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.moves.is_empty() {
+            return Err("expected at least one move".into());
+        }
+        unique(self.moves.iter().map(|m| m.name.as_str()), "move")?;
+        unique(self.moves.iter().map(|m| m.letter.as_str()), "move letter")?;
+        unique(self.responses.iter().map(|r| r.letter.as_str()), "response")?;
+        for (index, m) in self.moves.iter().enumerate() {
+            for &beaten in &m.beats {
+                if beaten == index {
+                    return Err(format!("`{}` cannot beat itself", m.name));
+                }
+                if self.moves[beaten].beats.contains(&index) {
+                    let other = &self.moves[beaten].name;
+                    return Err(format!("`{}` and `{other}` cannot beat each other", m.name));
+                }
+            }
+        }
+        for (theirs, m) in self.moves.iter().enumerate() {
+            let gets =
+                |outcome| (0..self.moves.len()).any(|mine| self.outcome(mine, theirs) == outcome);
+            if !gets(Outcome::Lose) {
+                return Err(format!("no move loses to `{}`", m.name));
+            }
+            if !gets(Outcome::Win) {
+                return Err(format!("no move beats `{}`", m.name));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    pub fn responses(&self) -> &[Response] {
+        &self.responses
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> usize {
+        self.outcome_scores[outcome as usize]
+    }
+
+    /// Outcome for the player of `mine` against `theirs`, both indices of moves.
+    pub fn outcome(&self, mine: usize, theirs: usize) -> Outcome {
+        if self.moves[mine].beats.contains(&theirs) {
+            Outcome::Win
+        } else if self.moves[theirs].beats.contains(&mine) {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// First move, in the order of the rules, getting `outcome` against `theirs`.
+    pub fn choose(&self, theirs: usize, outcome: Outcome) -> usize {
+        (0..self.moves.len())
+            .find(|&mine| self.outcome(mine, theirs) == outcome)
+            .expect("every outcome is possible against every move")
+    }
+
+    /// Reads the response of `round`, `None` if it doesn't stand for anything in that
+    /// interpretation.
+    pub fn play(&self, round: Round, interpretation: Interpretation) -> Option<Play> {
+        let response = &self.responses[round.response];
+        let chosen = match interpretation {
+            Interpretation::Move => response.play,
+            Interpretation::Outcome => self.choose(round.opponent, response.outcome?),
+        };
+        Some(Play {
+            opponent: round.opponent,
+            chosen,
+            outcome: self.outcome(chosen, round.opponent),
+        })
+    }
+
+    pub fn score(&self, play: &Play) -> usize {
+        self.moves[play.chosen].score + self.outcome_score(play.outcome)
+    }
+}
+impl Default for Rules {
+    fn default() -> Self {
+        Self::from_toml(DEFAULT).expect("the rules of the puzzle are valid")
+    }
+}
+
+fn unique<'a>(names: impl Iterator<Item = &'a str>, what: &str) -> Result<(), String> {
+    let mut seen = Vec::new();
+    for name in names {
+        if seen.contains(&name) {
+            return Err(format!("{what} `{name}` is defined twice"));
+        }
+        seen.push(name);
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    outcomes: OutcomeScores,
+    moves: Vec<MoveConfig>,
+    responses: Vec<ResponseConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OutcomeScores {
+    lose: usize,
+    draw: usize,
+    win: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MoveConfig {
+    name: String,
+    letter: String,
+    score: usize,
+    beats: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ResponseConfig {
+    letter: String,
+    play: String,
+    outcome: Option<Outcome>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Guide;
+
+    const RPSLS: &str = include_str!("../rules/rock-paper-scissors-lizard-spock.toml");
+
+    #[test]
+    fn default_test() {
+        let rules = Rules::default();
+        let names = rules
+            .moves()
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["rock", "paper", "scissors"]);
+        assert_eq!(rules.outcome(0, 2), Outcome::Win);
+        assert_eq!(rules.outcome(0, 1), Outcome::Lose);
+        assert_eq!(rules.choose(1, Outcome::Win), 2);
+    }
+
+    #[test]
+    fn lizard_spock_test() {
+        let rules = Rules::from_toml(RPSLS).unwrap();
+        let guide = Guide::parse("E X\nD Y\nA Z\n", rules.clone()).unwrap();
+        assert_eq!(guide.total(Interpretation::Move), Some(6));
+        assert_eq!(guide.total(Interpretation::Outcome), Some(16));
+
+        let guide = Guide::parse("E X\nD W\nA Z\n", rules).unwrap();
+        assert_eq!(guide.total(Interpretation::Move), Some(9));
+        assert_eq!(guide.total(Interpretation::Outcome), None);
+    }
+
+    #[test]
+    fn invalid_test() {
+        let invalid = |from: &str, to: &str| Rules::from_toml(&DEFAULT.replacen(from, to, 1));
+        let err = invalid(r#"beats = ["scissors"]"#, r#"beats = ["rock"]"#);
+        assert_eq!(err.unwrap_err(), "`rock` cannot beat itself");
+        let err = invalid(r#"beats = ["scissors"]"#, r#"beats = ["lizard"]"#);
+        assert_eq!(err.unwrap_err(), "unknown move `lizard`");
+        let err = invalid(r#"beats = ["rock"]"#, r#"beats = ["rock", "scissors"]"#);
+        assert_eq!(
+            err.unwrap_err(),
+            "`paper` and `scissors` cannot beat each other"
+        );
+        let err = invalid(r#"beats = ["paper"]"#, "beats = []");
+        assert_eq!(err.unwrap_err(), "no move beats `paper`");
+        let err = invalid(r#"letter = "Y""#, r#"letter = "X""#);
+        assert_eq!(err.unwrap_err(), "response `X` is defined twice");
+        assert!(invalid("win = 6", "win = -6").is_err());
+        assert!(invalid("[outcomes]", "[outcome]").is_err());
+    }
+}
@@ -0,0 +1,56 @@
+//! `size` groups of three elves, the compartments of every rucksack sharing exactly one item and
+//! the rucksacks of every group sharing exactly one badge.
+
+use aoc_common::gen::{Rng, SliceRandom, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    let mut rucksacks = String::new();
+    for _ in 0..size {
+        items.shuffle(rng);
+        let (badge, others) = items.split_first().unwrap();
+        // every elf picks from its own items, so only the badge can be common to all three
+        for own_items in others.chunks(others.len() / 3).take(3) {
+            rucksacks += &rucksack(rng, *badge, own_items);
+            rucksacks.push('\n');
+        }
+    }
+    rucksacks
+}
+
+fn rucksack(rng: &mut StdRng, badge: char, own_items: &[char]) -> String {
+    let mut items = own_items.to_vec();
+    items.push(badge);
+    items.shuffle(rng);
+    let shared = items.pop().unwrap();
+    let (left, right) = items.split_at(rng.gen_range(1..items.len()));
+    let len = rng.gen_range(3..=16);
+    [left, right]
+        .into_iter()
+        .map(|only_here| {
+            let mut compartment = vec![shared];
+            if only_here.contains(&badge) {
+                compartment.push(badge);
+            }
+            while compartment.len() < len {
+                compartment.push(*only_here.choose(rng).unwrap());
+            }
+            compartment.shuffle(rng);
+            compartment.into_iter().collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day03>(generate, 100);
+    }
+}
@@ -0,0 +1,97 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day03;
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("157")
+        .part2("70")];
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> impl Display {
+        split_and_find(rucksacks)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> impl Display {
+        group_and_find(rucksacks)
+    }
+}
+
+fn split_and_find(input: &[String]) -> usize {
+    let rucksacks_iter = input.iter().map(|rucksack| {
+        let (first, second) = rucksack.split_at(rucksack.len() / 2);
+        &HashSet::from_iter(first.chars()) & &HashSet::from_iter(second.chars())
+    });
+    calculate_score(rucksacks_iter)
+}
+
+fn group_and_find(input: &[String]) -> usize {
+    let rucksacks_iter = input
+        .chunks_exact(3)
+        .map(|chunk| chunk.iter().map(|item| HashSet::from_iter(item.chars())))
+        .filter_map(|mut chunk| {
+            let (item1, item2, item3) = (chunk.next()?, chunk.next()?, chunk.next()?);
+            Some(&(&item1 & &item2) & &item3)
+        });
+    calculate_score(rucksacks_iter)
+}
+
+fn calculate_score<I>(items_iter: I) -> usize
+where
+    I: Iterator<Item = HashSet<char>>,
+{
+    items_iter
+        .filter_map(|set| Some(get_value(*set.iter().next()?)))
+        .sum()
+}
+
+fn get_value(letter: char) -> usize {
+    match letter {
+        'A'..='Z' => letter as usize - 'A' as usize + 27,
+        'a'..='z' => letter as usize - 'a' as usize + 1,
+        _ => 0,
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .map(|l| match l.find(|ch: char| !ch.is_ascii_alphabetic()) {
+            Some(idx) => Err(source.error(&l[idx..], "expected only item letters")),
+            None if l.len() % 2 == 1 => Err(source.error(l, "compartments differ in size")),
+            None => Ok(l.into()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{group_and_find, parse, split_and_find, Day03};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test() {
+        let rucksacks = parse(fixture!("test-input")).unwrap();
+        assert_eq!(split_and_find(&rucksacks), 157)
+    }
+
+    #[test]
+    fn part2_test() {
+        let rucksacks = parse(fixture!("test-input")).unwrap();
+        assert_eq!(group_and_find(&rucksacks), 70)
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day03>();
+    }
+}
@@ -0,0 +1,27 @@
+//! `size` pairs of section ranges.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=99);
+        format!("{start}-{}", rng.gen_range(start..=99))
+    };
+    (0..size)
+        .map(|_| format!("{},{}\n", range(), range()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day04>(generate, 100);
+    }
+}
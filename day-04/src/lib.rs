@@ -0,0 +1,115 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, IntervalSet, ParseError, Solution, Source};
+use std::{fmt::Display, ops::RangeInclusive};
+
+pub struct Day04;
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("2")
+        .part2("4")];
+
+    type Input = Vec<RangePair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(ranges: &Self::Input) -> impl Display {
+        count_overlaps(ranges, is_full)
+    }
+
+    fn part2(ranges: &Self::Input) -> impl Display {
+        count_overlaps(ranges, is_partial)
+    }
+}
+
+pub type RangePair = (RangeInclusive<i64>, RangeInclusive<i64>);
+
+/// Number of sections assigned to at least one elf.
+pub fn count_covered(ranges: &[RangePair]) -> u64 {
+    ranges
+        .iter()
+        .flat_map(|(a, b)| [a.clone(), b.clone()])
+        .collect::<IntervalSet>()
+        .len()
+}
+
+fn count_overlaps<F>(ranges: &[RangePair], filter_fn: F) -> usize
+where
+    F: Fn(&&RangePair) -> bool,
+{
+    ranges.iter().filter(filter_fn).count()
+}
+
+fn is_full((a, b): &&RangePair) -> bool {
+    let (a, b) = (IntervalSet::from(a.clone()), IntervalSet::from(b.clone()));
+    a.subtract(&b).is_empty() || b.subtract(&a).is_empty()
+}
+
+fn is_partial((a, b): &&RangePair) -> bool {
+    let (a, b) = (IntervalSet::from(a.clone()), IntervalSet::from(b.clone()));
+    !a.intersect(&b).is_empty()
+}
+
+fn parse(input: &str) -> Result<Vec<RangePair>, ParseError> {
+    let source = Source::new(input);
+    let parse_range = |range| -> Result<RangeInclusive<i64>, ParseError> {
+        let (from, to) = source.split_once(range, "-")?;
+        Ok(source.number(from)?..=source.number(to)?)
+    };
+    input
+        .lines()
+        .map(|line| {
+            let (first, second) = source.split_once(line, ",")?;
+            Ok((parse_range(first)?, parse_range(second)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{count_covered, count_overlaps, is_full, is_partial, parse, Day04};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test() {
+        let ranges = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_overlaps(&ranges, is_full), 2)
+    }
+
+    #[test]
+    fn part2_test() {
+        let ranges = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_overlaps(&ranges, is_partial), 4)
+    }
+
+    #[test]
+    fn overlap_test() {
+        let pairs = [
+            (2..=8, 3..=7),
+            (6..=6, 4..=6),
+            (5..=7, 7..=9),
+            (2..=4, 6..=8),
+        ];
+        let full = pairs.iter().map(|pair| is_full(&pair)).collect::<Vec<_>>();
+        let partial = pairs
+            .iter()
+            .map(|pair| is_partial(&pair))
+            .collect::<Vec<_>>();
+        assert_eq!(full, [true, true, false, false]);
+        assert_eq!(partial, [true, true, true, false]);
+    }
+
+    #[test]
+    fn count_covered_test() {
+        let ranges = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_covered(&ranges), 8)
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day04>();
+    }
+}
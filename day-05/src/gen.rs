@@ -0,0 +1,61 @@
+//! Up to nine stacks of crates and `size` moves, every move lifting at most the crates its stack
+//! holds at that point.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut heights = (0..rng.gen_range(2..=9))
+        .map(|_| rng.gen_range(0..=8))
+        .collect::<Vec<usize>>();
+    if heights.iter().all(|&height| height == 0) {
+        heights[0] = 1;
+    }
+    let tallest = *heights.iter().max().unwrap();
+
+    let mut input = String::new();
+    for level in (0..tallest).rev() {
+        let cells = heights
+            .iter()
+            .map(|&height| match level < height {
+                true => format!("[{}]", rng.gen_range('A'..='Z')),
+                false => "   ".into(),
+            })
+            .collect::<Vec<_>>();
+        input += &cells.join(" ");
+        input.push('\n');
+    }
+    let numbers = (1..=heights.len())
+        .map(|stack| format!(" {stack} "))
+        .collect::<Vec<_>>();
+    input += &numbers.join(" ");
+    input += "\n\n";
+
+    for _ in 0..size {
+        let from = loop {
+            let stack = rng.gen_range(0..heights.len());
+            if heights[stack] > 0 {
+                break stack;
+            }
+        };
+        let to = (from + rng.gen_range(1..heights.len())) % heights.len();
+        let count = rng.gen_range(1..=heights[from]);
+        heights[from] -= count;
+        heights[to] += count;
+        input += &format!("move {count} from {} to {}\n", from + 1, to + 1);
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day05>(generate, 100);
+    }
+}
@@ -0,0 +1,226 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+
+use std::fmt::Display;
+pub struct Day05;
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("CMZ")
+        .part2("MCD")];
+
+    type Input = (Crates, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((crates, moves): &Self::Input) -> impl Display {
+        let mut crates = crates.clone();
+        crates.apply_moves_v9000(moves);
+        crates.get_message()
+    }
+
+    fn part2((crates, moves): &Self::Input) -> impl Display {
+        let mut crates = crates.clone();
+        crates.apply_moves_v9001(moves);
+        crates.get_message()
+    }
+}
+
+#[derive(Debug)]
+pub struct Move {
+    num_of_crates_to_move: usize,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Crates {
+    state: Vec<Vec<char>>,
+}
+impl Crates {
+    fn apply_moves_v9000(&mut self, moves: &[Move]) {
+        moves.iter().for_each(|m| {
+            (0..m.num_of_crates_to_move).for_each(|_| {
+                if let Some(ch) = self.state[m.from - 1].pop() {
+                    self.state[m.to - 1].push(ch);
+                }
+            });
+        })
+    }
+
+    fn apply_moves_v9001(&mut self, moves: &[Move]) {
+        moves.iter().for_each(|m| {
+            let lifted = (0..m.num_of_crates_to_move).fold(vec![], |mut acc, _| {
+                acc.push(self.state[m.from - 1].pop().unwrap());
+                acc
+            });
+            lifted.into_iter().rev().for_each(|ch| {
+                self.state[m.to - 1].push(ch);
+            });
+        });
+    }
+
+    fn get_message(&self) -> String {
+        self.state.iter().filter_map(|stack| stack.last()).collect()
+    }
+}
+impl Crates {
+    /// Parses the drawing of the stacks, one `[X]` cell every 4 columns above the stack numbers.
+    fn parse(source: &Source, text: &str) -> Result<Self, ParseError> {
+        let lines = text.lines().collect::<Vec<_>>();
+        let (numbers, drawing) = lines
+            .split_last()
+            .ok_or_else(|| source.error(text, "expected a drawing of the stacks"))?;
+        let num_of_buckets = numbers.split_whitespace().count();
+        let mut state = vec![vec![]; num_of_buckets];
+        for line in drawing.iter().rev() {
+            for idx in (0..line.len()).step_by(4) {
+                let cell = line
+                    .get(idx..line.len().min(idx + 3))
+                    .ok_or_else(|| source.error(line, "unexpected character"))?;
+                if cell.trim().is_empty() {
+                    continue;
+                }
+                let item = cell
+                    .strip_prefix('[')
+                    .and_then(|cell| cell.strip_suffix(']'))
+                    .and_then(|item| item.chars().next())
+                    .ok_or_else(|| source.error(cell, "expected a crate like `[A]`"))?;
+                state
+                    .get_mut(idx / 4)
+                    .ok_or_else(|| source.error(cell, "crate is not above a numbered stack"))?
+                    .push(item);
+            }
+        }
+        Ok(Self { state })
+    }
+}
+
+fn parse(content: &str) -> Result<(Crates, Vec<Move>), ParseError> {
+    let source = Source::new(content);
+    let (crates_str, moves_str) = source.split_once(content, "\n\n")?;
+    let crates = Crates::parse(&source, crates_str)?;
+    let re = regex::Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+    // replay the heights of the stacks, so that no move lifts crates that aren't there
+    let mut heights = crates.state.iter().map(Vec::len).collect::<Vec<_>>();
+    let moves = moves_str
+        .lines()
+        .map(|line| {
+            let captures = re
+                .captures(line)
+                .ok_or_else(|| source.error(line, "expected `move N from A to B`"))?;
+            let stack = |idx| -> Result<usize, ParseError> {
+                let text = captures.get(idx).unwrap().as_str();
+                match source.number(text)? {
+                    0 => Err(source.error(text, "stacks are numbered from 1")),
+                    stack if stack > crates.state.len() => {
+                        Err(source.error(text, "there is no such stack"))
+                    }
+                    stack => Ok(stack),
+                }
+            };
+            let count_text = captures.get(1).unwrap().as_str();
+            let m = Move {
+                num_of_crates_to_move: source.number(count_text)?,
+                from: stack(2)?,
+                to: stack(3)?,
+            };
+            let height = heights[m.from - 1];
+            if m.num_of_crates_to_move > height {
+                return Err(
+                    source.error(count_text, format!("stack {} holds only {height}", m.from))
+                );
+            }
+            heights[m.from - 1] -= m.num_of_crates_to_move;
+            heights[m.to - 1] += m.num_of_crates_to_move;
+            Ok(m)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((crates, moves))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, Crates, Day05, Move};
+    use aoc_common::{example::assert_examples, fixture};
+    use proptest::prelude::*;
+
+    /// A few stacks, and moves that never lift more crates than their stack holds.
+    fn crates_and_moves() -> impl Strategy<Value = (Crates, Vec<Move>)> {
+        let stacks = prop::collection::vec(
+            prop::collection::vec(prop::char::range('A', 'Z'), 0..6),
+            1..6,
+        );
+        let moves = prop::collection::vec(any::<(usize, usize, usize)>(), 0..20);
+        (stacks, moves).prop_map(|(state, moves)| {
+            let mut heights = state.iter().map(Vec::len).collect::<Vec<_>>();
+            let moves = moves
+                .into_iter()
+                .map(|(from, to, count)| {
+                    let (from, to) = (from % heights.len(), to % heights.len());
+                    let count = count % (heights[from] + 1);
+                    heights[from] -= count;
+                    heights[to] += count;
+                    Move {
+                        num_of_crates_to_move: count,
+                        from: from + 1,
+                        to: to + 1,
+                    }
+                })
+                .collect();
+            (Crates { state }, moves)
+        })
+    }
+
+    fn sorted_crates(crates: &Crates) -> Vec<char> {
+        let mut all = crates.state.concat();
+        all.sort();
+        all
+    }
+
+    #[test]
+    fn part1_test() {
+        let (mut crates, moves) = parse(fixture!("test-input")).unwrap();
+        crates.apply_moves_v9000(&moves);
+        assert_eq!(crates.get_message(), "CMZ");
+    }
+
+    #[test]
+    fn part2_test() {
+        let (mut crates, moves) = parse(fixture!("test-input")).unwrap();
+        crates.apply_moves_v9001(&moves);
+        assert_eq!(crates.get_message(), "MCD");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("[A]\n 1 \n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 18, "2"));
+
+        let err = parse("[A]\n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (5, 6, "2"));
+        assert_eq!(err.reason, "stack 2 holds only 1");
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day05>();
+    }
+
+    proptest! {
+        #[test]
+        fn crates_conserved_test((crates, moves) in crates_and_moves()) {
+            let mut v9000 = crates.clone();
+            v9000.apply_moves_v9000(&moves);
+            let mut v9001 = crates.clone();
+            v9001.apply_moves_v9001(&moves);
+            prop_assert_eq!(sorted_crates(&v9000), sorted_crates(&crates));
+            prop_assert_eq!(sorted_crates(&v9001), sorted_crates(&crates));
+            let heights = |crates: &Crates| crates.state.iter().map(Vec::len).collect::<Vec<_>>();
+            prop_assert_eq!(heights(&v9000), heights(&v9001));
+        }
+    }
+}
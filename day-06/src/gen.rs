@@ -0,0 +1,31 @@
+//! A datastream of at least `size` letters, drawn from too few letters to hold a start-of-message
+//! marker until one is inserted near the end.
+
+use aoc_common::gen::{Rng, SliceRandom, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut letters = ('a'..='z').collect::<Vec<_>>();
+    letters.shuffle(rng);
+    let noise = &letters[..rng.gen_range(2..=8)];
+    let mut stream = (0..size)
+        .map(|_| *noise.choose(rng).unwrap())
+        .collect::<String>();
+    letters.shuffle(rng);
+    stream.extend(&letters[..14]);
+    stream.extend((0..rng.gen_range(0..=size / 10)).map(|_| rng.gen_range('a'..='z')));
+    stream + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day06>(generate, 100);
+    }
+}
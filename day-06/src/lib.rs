@@ -0,0 +1,104 @@
+pub mod gen;
+
+use aoc_common::{Example, ParseError, Solution, Source};
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day06;
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example1", "mjqjpqmgbljsphdztnvjfqwrcgsmlb")
+            .part1("7")
+            .part2("19"),
+        Example::new("example2", "bvwbjplbgvbhsrlpgdmjqwftvncz")
+            .part1("5")
+            .part2("23"),
+        Example::new("example3", "nppdvjthqldpwncqszvftbrmjlhg")
+            .part1("6")
+            .part2("23"),
+        Example::new("example4", "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")
+            .part1("10")
+            .part2("29"),
+        Example::new("example5", "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")
+            .part1("11")
+            .part2("26"),
+    ];
+
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(chars: &Self::Input) -> impl Display {
+        find_marker(chars, 4)
+    }
+
+    fn part2(chars: &Self::Input) -> impl Display {
+        find_marker(chars, 14)
+    }
+}
+
+fn find_marker(chars: &[char], window_size: usize) -> usize {
+    chars
+        .windows(window_size)
+        .position(|window| HashSet::<&char>::from_iter(window.iter()).len() == window_size)
+        .unwrap()
+        + window_size
+}
+
+fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+    let stream = input.trim_end();
+    match stream.find(|ch: char| !ch.is_ascii_lowercase()) {
+        Some(idx) => Err(Source::new(input).error(&stream[idx..idx + 1], "expected a letter")),
+        None => Ok(stream.chars().collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_marker, Day06};
+    use aoc_common::example::assert_examples;
+
+    fn to_vec(input: &str) -> Vec<char> {
+        input.chars().collect()
+    }
+
+    #[test]
+    fn part1_test() {
+        assert_eq!(find_marker(&to_vec("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 4), 7);
+        assert_eq!(find_marker(&to_vec("bvwbjplbgvbhsrlpgdmjqwftvncz"), 4), 5);
+        assert_eq!(find_marker(&to_vec("nppdvjthqldpwncqszvftbrmjlhg"), 4), 6);
+        assert_eq!(
+            find_marker(&to_vec("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 4),
+            10
+        );
+        assert_eq!(
+            find_marker(&to_vec("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 4),
+            11
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        assert_eq!(
+            find_marker(&to_vec("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 14),
+            19
+        );
+        assert_eq!(find_marker(&to_vec("bvwbjplbgvbhsrlpgdmjqwftvncz"), 14), 23);
+        assert_eq!(find_marker(&to_vec("nppdvjthqldpwncqszvftbrmjlhg"), 14), 23);
+        assert_eq!(
+            find_marker(&to_vec("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 14),
+            29
+        );
+        assert_eq!(
+            find_marker(&to_vec("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 14),
+            26
+        );
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day06>();
+    }
+}
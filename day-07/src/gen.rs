@@ -0,0 +1,111 @@
+//! A terminal session exploring `size` directories, using between 40000000 and 70000000 of the
+//! 70000000 of the disk so that something has to be deleted.
+
+use aoc_common::gen::{Rng, SliceRandom, StdRng};
+use std::collections::HashSet;
+
+pub const MIN_SIZE: usize = 1;
+
+struct Dir {
+    name: String,
+    files: Vec<(String, usize)>,
+    dirs: Vec<usize>,
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut dirs = vec![Dir {
+        name: "/".into(),
+        files: Vec::new(),
+        dirs: Vec::new(),
+    }];
+    let mut names = vec![HashSet::new()];
+    for idx in 1..size.max(MIN_SIZE) {
+        let parent = rng.gen_range(0..idx);
+        let name = unique_name(rng, &mut names[parent], false);
+        dirs[parent].dirs.push(idx);
+        dirs.push(Dir {
+            name,
+            files: Vec::new(),
+            dirs: Vec::new(),
+        });
+        names.push(HashSet::new());
+    }
+    for (dir, names) in dirs.iter_mut().zip(&mut names) {
+        for _ in 0..rng.gen_range(0..=4) {
+            let name = unique_name(rng, names, true);
+            dir.files.push((name, rng.gen_range(1000..=300_000)));
+        }
+    }
+
+    let used = dirs
+        .iter()
+        .flat_map(|dir| &dir.files)
+        .map(|&(_, size)| size)
+        .sum::<usize>();
+    if used > 30_000_000 {
+        for (_, size) in dirs.iter_mut().flat_map(|dir| &mut dir.files) {
+            *size = (*size * 30_000_000 / used).max(1);
+        }
+    }
+    let used = dirs
+        .iter()
+        .flat_map(|dir| &dir.files)
+        .map(|&(_, size)| size)
+        .sum::<usize>();
+    let padding = 40_000_001 - used + rng.gen_range(0..20_000_000);
+    let idx = rng.gen_range(0..dirs.len());
+    let name = unique_name(rng, &mut names[idx], true);
+    dirs[idx].files.push((name, padding));
+
+    let mut session = String::new();
+    write_session(rng, &dirs, 0, &mut session);
+    session
+}
+
+fn unique_name(rng: &mut StdRng, taken: &mut HashSet<String>, is_file: bool) -> String {
+    loop {
+        let mut name = (0..rng.gen_range(1..=8))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect::<String>();
+        if is_file && rng.gen_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range('a'..='z')));
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn write_session(rng: &mut StdRng, dirs: &[Dir], idx: usize, session: &mut String) {
+    let dir = &dirs[idx];
+    *session += &format!("$ cd {}\n$ ls\n", dir.name);
+    let mut listing = dir
+        .dirs
+        .iter()
+        .map(|&sub_dir| format!("dir {}\n", dirs[sub_dir].name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| format!("{size} {name}\n")),
+        )
+        .collect::<Vec<_>>();
+    listing.shuffle(rng);
+    session.extend(listing);
+    for &sub_dir in &dir.dirs {
+        write_session(rng, dirs, sub_dir, session);
+        *session += "$ cd ..\n";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day07>(generate, 100);
+    }
+}
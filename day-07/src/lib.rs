@@ -0,0 +1,213 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+pub struct Day07;
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("95437")
+        .part2("24933642")];
+
+    type Input = Rc<RefCell<FileEntry>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(root: &Self::Input) -> impl Display {
+        find_sum_of_dirs_bellow_limit(root)
+    }
+
+    fn part2(root: &Self::Input) -> impl Display {
+        find_size_of_dir_to_delete(root)
+    }
+}
+
+fn find_size_of_dir_to_delete(root: &Rc<RefCell<FileEntry>>) -> usize {
+    let current_used_size = root.borrow().size();
+    let target_size = 30000000 - (70000000 - current_used_size);
+    find_size_of_dir_to_delete_rec(root, usize::MAX, target_size)
+}
+
+fn find_size_of_dir_to_delete_rec(
+    root: &Rc<RefCell<FileEntry>>,
+    min: usize,
+    limit: usize,
+) -> usize {
+    usize::min(
+        match &*root.borrow() {
+            FileEntry::Dir((_, items, _)) => items
+                .iter()
+                .filter(|item| item.borrow().is_dir())
+                .map(|item| {
+                    let size = item.borrow().size();
+                    let curr_min = if size >= limit && size < min {
+                        size
+                    } else {
+                        min
+                    };
+                    usize::min(
+                        find_size_of_dir_to_delete_rec(item, curr_min, limit),
+                        curr_min,
+                    )
+                })
+                .min()
+                .unwrap_or(min),
+            FileEntry::File(_) => usize::MAX,
+        },
+        min,
+    )
+}
+
+fn find_sum_of_dirs_bellow_limit(root: &Rc<RefCell<FileEntry>>) -> usize {
+    let mut sum = 0;
+    find_sum_of_dirs_bellow_limit_rec(root, &mut sum, 100000);
+    sum
+}
+
+fn find_sum_of_dirs_bellow_limit_rec(root: &Rc<RefCell<FileEntry>>, sum: &mut usize, limit: usize) {
+    match &*root.borrow() {
+        FileEntry::Dir((_, items, _)) => items.iter().for_each(|item| {
+            if !item.borrow().is_dir() {
+                return;
+            }
+            let size = item.borrow().size();
+            if size < limit {
+                *sum += size;
+            }
+            find_sum_of_dirs_bellow_limit_rec(item, sum, limit);
+        }),
+        FileEntry::File(_) => {}
+    }
+}
+
+type DirInfo = (
+    String,
+    Vec<Rc<RefCell<FileEntry>>>,
+    Option<Rc<RefCell<FileEntry>>>,
+);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FileEntry {
+    File((String, usize, Option<Rc<RefCell<FileEntry>>>)),
+    Dir(DirInfo),
+}
+impl FileEntry {
+    fn parent(&self) -> Option<Rc<RefCell<FileEntry>>> {
+        match self {
+            FileEntry::Dir(dir_info) => {
+                let (_dir_name, _items, parent) = dir_info;
+                parent.clone()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn items(&self) -> &Vec<Rc<RefCell<FileEntry>>> {
+        match self {
+            FileEntry::Dir((_dir_name, items, _parent)) => items,
+            _ => unreachable!(),
+        }
+    }
+
+    fn add_item(&mut self, item: FileEntry) {
+        match self {
+            FileEntry::Dir(dir_info) => {
+                let (_dir_name, items, _parent) = dir_info;
+                items.push(Rc::new(RefCell::new(item)));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            FileEntry::Dir((_, items, _)) => items.iter().map(|item| item.borrow().size()).sum(),
+            FileEntry::File((_, size, _)) => *size,
+        }
+    }
+
+    fn is_dir(&self) -> bool {
+        matches!(self, FileEntry::Dir(_))
+    }
+}
+
+fn parse(input: &str) -> Result<Rc<RefCell<FileEntry>>, ParseError> {
+    let source = Source::new(input);
+    let mut root = None;
+    let mut current: Option<Rc<RefCell<FileEntry>>> = None;
+    let mut lines_iter = input.lines().peekable();
+    while let Some(cmd) = lines_iter.next() {
+        if let Some(dir_name) = cmd.strip_prefix("$ cd ") {
+            if dir_name == "/" {
+                if root.is_none() {
+                    root = Some(Rc::new(RefCell::new(FileEntry::Dir((
+                        dir_name.into(),
+                        vec![],
+                        None,
+                    )))));
+                }
+                current = root.clone();
+                continue;
+            }
+            let curr_dir = current.ok_or_else(|| {
+                source.error(cmd, "no current directory, expected `$ cd /` first")
+            })?;
+            current = if dir_name == ".." {
+                curr_dir.borrow().parent()
+            } else {
+                let found = curr_dir.borrow()
+                    .items()
+                    .iter()
+                    .find(|dir| matches!(&*dir.borrow(), FileEntry::Dir((name, _,_)) if name == dir_name))
+                    .cloned();
+                Some(found.ok_or_else(|| source.error(dir_name, "no such directory"))?)
+            };
+        } else if cmd == "$ ls" {
+            let current_ref = current.as_ref().ok_or_else(|| {
+                source.error(cmd, "no current directory, expected `$ cd /` first")
+            })?;
+            while let Some(next_line) = lines_iter.peek() {
+                if next_line.starts_with("$ ") {
+                    break;
+                }
+                let item_str = lines_iter.next().unwrap();
+                let item = if let Some(dir_name) = item_str.strip_prefix("dir ") {
+                    FileEntry::Dir((dir_name.into(), vec![], current.clone()))
+                } else {
+                    let (size, file_name) = source.split_once(item_str, " ")?;
+                    FileEntry::File((file_name.into(), source.number(size)?, current.clone()))
+                };
+                current_ref.borrow_mut().add_item(item);
+            }
+        } else {
+            return Err(source.error(cmd, "expected `$ cd` or `$ ls`"));
+        }
+    }
+    root.ok_or_else(|| source.eof("expected `$ cd /`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_size_of_dir_to_delete, find_sum_of_dirs_bellow_limit, parse, Day07};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test() {
+        let root = parse(fixture!("test-input")).unwrap();
+        assert_eq!(find_sum_of_dirs_bellow_limit(&root), 95437);
+    }
+
+    #[test]
+    fn part2_test() {
+        let root = parse(fixture!("test-input")).unwrap();
+        assert_eq!(find_size_of_dir_to_delete(&root), 24933642);
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day07>();
+    }
+}
@@ -0,0 +1,32 @@
+//! A forest of `size` by `size` trees, at least 3 by 3 so that some tree is inside it.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 3;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(MIN_SIZE);
+    (0..size)
+        .map(|_| {
+            let row = (0..size)
+                .map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap())
+                .collect::<String>();
+            row + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day08>(generate, 50);
+        for size in 0..3 {
+            assert_generates::<Day08>(generate, size);
+        }
+    }
+}
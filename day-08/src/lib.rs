@@ -0,0 +1,103 @@
+pub mod gen;
+
+use aoc_common::{fixture, grid::OFFSETS4, Example, Grid, ParseError, Solution};
+
+use std::fmt::Display;
+pub struct Day08;
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("21")
+        .part2("8")];
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> impl Display {
+        count_visible_trees(map)
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        find_max_scenic_score(map)
+    }
+}
+
+fn count_visible_trees(map: &Grid<u8>) -> usize {
+    let (size_x, size_y) = (map.width(), map.height());
+    let items_on_edge = size_x * size_y - size_x.saturating_sub(2) * size_y.saturating_sub(2);
+    items_on_edge
+        + (1..size_y.saturating_sub(1))
+            .flat_map(|y| (1..size_x.saturating_sub(1)).map(move |x| (y, x)))
+            .filter(|&pos| is_visible(map, pos))
+            .count()
+}
+
+fn is_visible(map: &Grid<u8>, pos: (usize, usize)) -> bool {
+    OFFSETS4
+        .into_iter()
+        .any(|diff| map.ray(pos, diff).all(|(_, &tree)| tree < map[pos]))
+}
+
+/// 0 when every tree is on the edge, as those see nothing one way.
+fn find_max_scenic_score(map: &Grid<u8>) -> usize {
+    (1..map.height().saturating_sub(1))
+        .flat_map(|y| (1..map.width().saturating_sub(1)).map(move |x| (y, x)))
+        .map(|pos| calculate_single_score(map, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+fn calculate_single_score(map: &Grid<u8>, pos: (usize, usize)) -> usize {
+    OFFSETS4
+        .into_iter()
+        .map(|diff| {
+            let mut count = 0;
+            for (_, &tree) in map.ray(pos, diff) {
+                count += 1;
+                if tree >= map[pos] {
+                    break;
+                }
+            }
+            count
+        })
+        .product()
+}
+
+fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |ch| Some(ch.to_digit(10)? as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{count_visible_trees, find_max_scenic_score, parse, Day08};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test() {
+        let map = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_visible_trees(&map), 21)
+    }
+
+    #[test]
+    fn part2_test() {
+        let map = parse(fixture!("test-input")).unwrap();
+        assert_eq!(find_max_scenic_score(&map), 8)
+    }
+
+    #[test]
+    fn small_forest_test() {
+        for (input, visible) in [("5\n", 1), ("12\n34\n", 4), ("123\n", 3)] {
+            let map = parse(input).unwrap();
+            assert_eq!(count_visible_trees(&map), visible);
+            assert_eq!(find_max_scenic_score(&map), 0);
+        }
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day08>();
+    }
+}
@@ -0,0 +1,26 @@
+//! `size` motions of the head of the rope.
+
+use aoc_common::gen::{Rng, SliceRandom, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let dir = ["R", "L", "U", "D"].choose(rng).unwrap();
+            format!("{dir} {}\n", rng.gen_range(1..=20))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day09>(generate, 100);
+    }
+}
@@ -0,0 +1,188 @@
+pub mod gen;
+
+use aoc_common::{
+    animation::Render, fixture, Direction4, Example, Grid, ParseError, Point2, Solution, Source,
+};
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day09;
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("test-input", fixture!("test-input"))
+            .part1("13")
+            .part2("1"),
+        Example::new("test-input2", fixture!("test-input2")).part2("36"),
+    ];
+
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(moves: &Self::Input) -> impl Display {
+        count_visited_for_tail::<2>(moves)
+    }
+
+    fn part2(moves: &Self::Input) -> impl Display {
+        count_visited_for_tail::<10>(moves)
+    }
+}
+
+fn count_visited_for_tail<const N: usize>(moves: &[Move]) -> usize {
+    let mut knots = [Point2::ORIGIN; N];
+    let mut tail_visited = HashSet::from([knots[0]]);
+    for &(dir, steps) in moves {
+        for _ in 0..steps {
+            move_head(&mut knots, dir);
+            tail_visited.insert(knots[N - 1]);
+        }
+    }
+    tail_visited.len()
+}
+
+fn move_head(knots: &mut [Point2], dir: Direction4) {
+    knots[0] += dir.offset();
+    for next_idx in 1..knots.len() {
+        knots[next_idx] = move_tail(knots[next_idx - 1], knots[next_idx]);
+    }
+}
+
+fn move_tail(head: Point2, tail: Point2) -> Point2 {
+    if head.chebyshev_distance(tail) > 1 {
+        tail + (head - tail).signum()
+    } else {
+        tail
+    }
+}
+
+pub type Move = (Direction4, usize);
+
+/// The ten knots of part 2 following the head one step at a time.
+struct Rope {
+    steps: Vec<Direction4>,
+    next_step: usize,
+    knots: [Point2; 10],
+    tail_visited: HashSet<Point2>,
+    /// Corners of the area covered by the head during the whole animation.
+    min: Point2,
+    max: Point2,
+}
+impl Render for Rope {
+    fn render(&self) -> String {
+        let (w, h) = (
+            (self.max.x - self.min.x + 1) as usize,
+            (self.max.y - self.min.y + 1) as usize,
+        );
+        let mut map = Grid::new(w, h, '.');
+        let mut draw = |pos: Point2, ch| {
+            map[((pos.y - self.min.y) as usize, (pos.x - self.min.x) as usize)] = ch;
+        };
+        self.tail_visited.iter().for_each(|&pos| draw(pos, '#'));
+        draw(Point2::ORIGIN, 's');
+        for (idx, &knot) in self.knots.iter().enumerate().rev() {
+            draw(knot, b"H123456789"[idx] as char);
+        }
+        map.to_string()
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&dir) = self.steps.get(self.next_step) else {
+            return false;
+        };
+        move_head(&mut self.knots, dir);
+        self.tail_visited.insert(self.knots[9]);
+        self.next_step += 1;
+        true
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Render>, ParseError> {
+    let steps = parse(input)?
+        .into_iter()
+        .flat_map(|(dir, steps)| std::iter::repeat_n(dir, steps))
+        .collect::<Vec<_>>();
+    let (mut min, mut max, mut head) = (Point2::ORIGIN, Point2::ORIGIN, Point2::ORIGIN);
+    for dir in &steps {
+        head += dir.offset();
+        min = Point2::new(min.x.min(head.x), min.y.min(head.y));
+        max = Point2::new(max.x.max(head.x), max.y.max(head.y));
+    }
+    Ok(Box::new(Rope {
+        steps,
+        next_step: 0,
+        knots: [Point2::ORIGIN; 10],
+        tail_visited: HashSet::from([Point2::ORIGIN]),
+        min,
+        max,
+    }))
+}
+
+fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let (dir, steps) = source.split_once(line, " ")?;
+            let dir = match dir {
+                "R" => Direction4::Right,
+                "L" => Direction4::Left,
+                "U" => Direction4::Up,
+                "D" => Direction4::Down,
+                _ => return Err(source.error(dir, "expected one of R, L, U, D")),
+            };
+            Ok((dir, source.number(steps)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{animation, count_visited_for_tail, parse, Day09};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test() {
+        let moves = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_visited_for_tail::<2>(&moves), 13);
+    }
+
+    #[test]
+    fn part2_test1() {
+        let moves = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_visited_for_tail::<10>(&moves), 1);
+    }
+
+    #[test]
+    fn part2_test2() {
+        let moves = parse(fixture!("test-input2")).unwrap();
+        assert_eq!(count_visited_for_tail::<10>(&moves), 36);
+    }
+
+    #[test]
+    fn render_test() {
+        let mut rope = animation(fixture!("test-input")).unwrap();
+        for _ in 0..4 {
+            assert!(rope.step());
+        }
+        assert_eq!(
+            rope.render(),
+            "\
+......
+......
+......
+......
+4321H."
+        );
+        for _ in 4..24 {
+            assert!(rope.step());
+        }
+        assert!(!rope.step());
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day09>();
+    }
+}
@@ -0,0 +1,32 @@
+//! A program lasting exactly the 240 cycles the CRT takes to draw, so `size` is ignored.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, _size: usize) -> String {
+    let mut program = String::new();
+    let mut cycles_left = 240;
+    while cycles_left > 0 {
+        if cycles_left >= 2 && rng.gen_bool(0.6) {
+            program += &format!("addx {}\n", rng.gen_range(-20..=20));
+            cycles_left -= 2;
+        } else {
+            program += "noop\n";
+            cycles_left -= 1;
+        }
+    }
+    program
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day10>(generate, 0);
+    }
+}
@@ -0,0 +1,272 @@
+pub mod gen;
+
+use aoc_common::{animation::Render, fixture, Example, ParseError, Solution, Source};
+
+use std::fmt::Display;
+pub struct Day10;
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("test-input2", fixture!("test-input2")).part1("13140").part2("##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######....."),
+    ];
+
+    type Input = Vec<Op>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(ops: &Self::Input) -> impl Display {
+        Cpu::new(ops).sum_signal_strengths(&[20, 60, 100, 140, 180, 220])
+    }
+
+    fn part2(ops: &Self::Input) -> impl Display {
+        let mut cpu = Cpu::new(ops);
+        cpu.run_til_end();
+        cpu.get_crt_output()
+    }
+}
+
+#[derive(Debug)]
+struct Cpu {
+    cycle: usize,
+    register: isize,
+    current_op_idx: usize,
+    current_op_remaining: usize,
+    signal_strength: isize,
+    ops: Vec<Op>,
+    crt_output: Vec<Vec<char>>,
+}
+impl Cpu {
+    fn new(ops: &[Op]) -> Self {
+        Self {
+            cycle: 0,
+            current_op_idx: 0,
+            current_op_remaining: ops.first().unwrap().get_duration(),
+            signal_strength: 0,
+            register: 1,
+            ops: ops.to_vec(),
+            crt_output: vec![vec!['.'; 40]; 6],
+        }
+    }
+
+    fn next_tick(&mut self) {
+        self.draw_pixel();
+        self.cycle += 1;
+        self.current_op_remaining -= 1;
+        self.signal_strength = self.compute_signal_strength();
+        if self.current_op_remaining == 0 {
+            if let Some(Op::Addx(value)) = self.ops.get(self.current_op_idx) {
+                self.register += value;
+            }
+            self.current_op_idx += 1;
+            self.current_op_remaining = self
+                .ops
+                .get(self.current_op_idx)
+                .unwrap_or(&Op::Noop)
+                .get_duration();
+        }
+    }
+
+    fn run_til_end(&mut self) {
+        while self.current_op_idx < self.ops.len() {
+            self.next_tick();
+        }
+    }
+
+    fn draw_pixel(&mut self) {
+        let w = self.cycle % 40;
+        let h = self.cycle / 40;
+        if w as isize >= self.register - 1 && w as isize <= self.register + 1 {
+            self.crt_output[h][w] = '#';
+        }
+    }
+
+    fn get_crt_output(&self) -> String {
+        let rows = self
+            .crt_output
+            .iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        rows.join("\n")
+    }
+
+    fn compute_signal_strength(&self) -> isize {
+        self.cycle as isize * self.register
+    }
+
+    fn sum_signal_strengths(&mut self, cycle_samples: &[usize]) -> isize {
+        (0..*cycle_samples.last().unwrap())
+            .filter_map(|_| {
+                self.next_tick();
+                if cycle_samples.contains(&self.cycle) {
+                    Some(self.signal_strength)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+impl Render for Cpu {
+    fn render(&self) -> String {
+        format!(
+            "cycle {}, X = {}\n{}",
+            self.cycle,
+            self.register,
+            self.get_crt_output()
+        )
+    }
+
+    /// Draws one pixel, until the program is over.
+    fn step(&mut self) -> bool {
+        if self.current_op_idx == self.ops.len() {
+            return false;
+        }
+        self.next_tick();
+        true
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Render>, ParseError> {
+    Ok(Box::new(Cpu::new(&parse(input)?)))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Op {
+    Noop,
+    Addx(isize),
+}
+impl Op {
+    fn get_duration(&self) -> usize {
+        match self {
+            Op::Addx(_) => 2,
+            Op::Noop => 1,
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Op>, ParseError> {
+    let source = Source::new(input);
+    let ops = input
+        .lines()
+        .map(|line| {
+            if line == "noop" {
+                Ok(Op::Noop)
+            } else {
+                Ok(Op::Addx(
+                    source.number(source.strip_prefix(line, "addx ")?)?,
+                ))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if ops.is_empty() {
+        return Err(source.eof("expected at least one instruction"));
+    }
+    Ok(ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{animation, parse, Cpu, Day10};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test1() {
+        let ops = parse(fixture!("test-input")).unwrap();
+        let mut cpu = Cpu::new(&ops);
+
+        cpu.next_tick();
+        assert_eq!(cpu.register, 1);
+        cpu.next_tick();
+        assert_eq!(cpu.register, 1);
+        cpu.next_tick();
+        assert_eq!(cpu.register, 4);
+        cpu.next_tick();
+        assert_eq!(cpu.register, 4);
+        cpu.next_tick();
+        assert_eq!(cpu.register, -1);
+    }
+
+    #[test]
+    fn part1_test2() {
+        let ops = parse(fixture!("test-input2")).unwrap();
+        let mut cpu = Cpu::new(&ops);
+
+        fn run_n_cycles(cpu: &mut Cpu, n: usize) {
+            (0..n).for_each(|_| cpu.next_tick());
+        }
+
+        run_n_cycles(&mut cpu, 20);
+        assert_eq!(cpu.signal_strength, 420);
+        cpu = Cpu::new(&ops);
+        run_n_cycles(&mut cpu, 60);
+        assert_eq!(cpu.signal_strength, 1140);
+        cpu = Cpu::new(&ops);
+        run_n_cycles(&mut cpu, 100);
+        assert_eq!(cpu.signal_strength, 1800);
+        cpu = Cpu::new(&ops);
+        run_n_cycles(&mut cpu, 140);
+        assert_eq!(cpu.signal_strength, 2940);
+        cpu = Cpu::new(&ops);
+        run_n_cycles(&mut cpu, 180);
+        assert_eq!(cpu.signal_strength, 2880);
+        cpu = Cpu::new(&ops);
+        run_n_cycles(&mut cpu, 220);
+        assert_eq!(cpu.signal_strength, 3960);
+        cpu = Cpu::new(&ops);
+
+        assert_eq!(
+            cpu.sum_signal_strengths(&[20, 60, 100, 140, 180, 220]),
+            13140
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let ops = parse(fixture!("test-input2")).unwrap();
+        let mut cpu = Cpu::new(&ops);
+        cpu.run_til_end();
+        assert_eq!(
+            cpu.get_crt_output(),
+            r#"
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+"#
+            .trim()
+        )
+    }
+
+    #[test]
+    fn render_test() {
+        let mut crt = animation(fixture!("test-input2")).unwrap();
+        for _ in 0..3 {
+            assert!(crt.step());
+        }
+        assert!(crt
+            .render()
+            .starts_with("cycle 3, X = 16\n##......................................\n"));
+        let mut cycles = 3;
+        while crt.step() {
+            cycles += 1;
+        }
+        assert_eq!(cycles, 240);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.reason, "expected at least one instruction");
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day10>();
+    }
+}
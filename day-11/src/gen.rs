@@ -0,0 +1,122 @@
+//! Between 2 and 9 monkeys, `size` of them if possible.
+//!
+//! Every monkey tests for a distinct prime: the worry levels of part 2 are kept modulo the
+//! product of all of them, which must stay small enough to be squared. Part 1 has no such bound,
+//! so monkeys are drawn again until its worry levels fit.
+
+use aoc_common::gen::{Rng, SliceRandom, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+struct Monkey {
+    items: Vec<isize>,
+    /// `None` squares the worry level.
+    multiply: Option<isize>,
+    add: isize,
+    divisible_by: isize,
+    targets: [usize; 2],
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = size.clamp(2, 9);
+    let monkeys = loop {
+        let monkeys = random_monkeys(rng, count);
+        if part1_fits(&monkeys) {
+            break monkeys;
+        }
+    };
+    let monkeys = monkeys
+        .iter()
+        .enumerate()
+        .map(|(idx, monkey)| {
+            let items = monkey
+                .items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>();
+            let operation = match monkey.multiply {
+                None => "* old".into(),
+                Some(1) => format!("+ {}", monkey.add),
+                Some(factor) => format!("* {factor}"),
+            };
+            format!(
+                "Monkey {idx}:
+  Starting items: {}
+  Operation: new = old {operation}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+                items.join(", "),
+                monkey.divisible_by,
+                monkey.targets[0],
+                monkey.targets[1],
+            )
+        })
+        .collect::<Vec<_>>();
+    monkeys.join("\n")
+}
+
+fn random_monkeys(rng: &mut StdRng, count: usize) -> Vec<Monkey> {
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    primes.shuffle(rng);
+    (0..count)
+        .map(|idx| {
+            let (multiply, add) = match rng.gen_range(0..8) {
+                0 => (None, 0),
+                1..=2 => (Some(rng.gen_range(2..=19)), 0),
+                _ => (Some(1), rng.gen_range(1..=8)),
+            };
+            let mut others = (0..count).filter(|&other| other != idx).collect::<Vec<_>>();
+            others.shuffle(rng);
+            Monkey {
+                items: (0..rng.gen_range(1..=8))
+                    .map(|_| rng.gen_range(50..=99))
+                    .collect(),
+                multiply,
+                add,
+                divisible_by: primes[idx],
+                targets: [others[0], *others.get(1).unwrap_or(&others[0])],
+            }
+        })
+        .collect()
+}
+
+/// Plays the 20 rounds of part 1, checking that no worry level overflows.
+fn part1_fits(monkeys: &[Monkey]) -> bool {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<_>>();
+    for _ in 0..20 {
+        for (idx, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[idx]) {
+                let Some(item) = item
+                    .checked_mul(monkey.multiply.unwrap_or(item))
+                    .and_then(|item| item.checked_add(monkey.add))
+                else {
+                    return false;
+                };
+                let item = item / 3;
+                let target = match item % monkey.divisible_by {
+                    0 => monkey.targets[0],
+                    _ => monkey.targets[1],
+                };
+                items[target].push(item);
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day11>(generate, 8);
+    }
+}
@@ -0,0 +1,180 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+use std::{collections::VecDeque, fmt::Display};
+
+pub struct Day11;
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("10605")
+        .part2("2713310158")];
+
+    type Input = Game;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(game: &Self::Input) -> impl Display {
+        let mut game = game.clone();
+        game.play_n_rounds(20, true);
+        game.score()
+    }
+
+    fn part2(game: &Self::Input) -> impl Display {
+        let mut game = game.clone();
+        game.play_n_rounds(10000, false);
+        game.score()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    monkeys: Vec<Monkey>,
+}
+impl Game {
+    fn play_n_rounds(&mut self, n: usize, divide_by_3: bool) {
+        let prime_product = self.monkeys.iter().map(|m| m.divisible_by).product();
+        for _ in 0..n {
+            for m_idx in 0..self.monkeys.len() {
+                let items = self.monkeys[m_idx].inspect(divide_by_3, prime_product);
+                for (target_monkey, item) in items {
+                    self.monkeys[target_monkey].items.push_back(item);
+                }
+            }
+        }
+    }
+
+    fn score(&self) -> usize {
+        let mut monkeys = self.monkeys.clone();
+        monkeys.sort_by_key(|x| x.inspected_items);
+
+        monkeys
+            .into_iter()
+            .rev()
+            .take(2)
+            .map(|x| x.inspected_items)
+            .product()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Monkey {
+    items: VecDeque<isize>,
+    operation: Op,
+    divisible_by: isize,
+    true_target: usize,
+    false_target: usize,
+    inspected_items: usize,
+}
+impl Monkey {
+    fn inspect(&mut self, divide_by_3: bool, prime_product: isize) -> Vec<(usize, isize)> {
+        let mut result = Vec::new();
+        while let Some(mut item) = self.items.pop_front() {
+            match &self.operation {
+                Op::Add(value) => item += value,
+                Op::Multiply(value) => item *= value,
+                Op::Square => item *= item,
+            }
+            match divide_by_3 {
+                true => item /= 3,
+                false => item %= prime_product,
+            }
+            let target_monkey = match item % self.divisible_by {
+                0 => self.true_target,
+                _ => self.false_target,
+            };
+            self.inspected_items += 1;
+            result.push((target_monkey, item));
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Op {
+    Add(isize),
+    Multiply(isize),
+    Square,
+}
+
+fn parse(input: &str) -> Result<Game, ParseError> {
+    let source = Source::new(input);
+    let monkeys = input
+        .split_terminator("\n\n")
+        .map(|monkey| {
+            let lines = monkey.lines().collect::<Vec<_>>();
+            let line = |idx: usize, prefix| -> Result<&str, ParseError> {
+                let line = lines.get(idx).ok_or_else(|| {
+                    source.error(&monkey[monkey.len()..], format!("expected `{prefix}`"))
+                })?;
+                source.strip_prefix(line, prefix)
+            };
+            let items = line(1, "  Starting items: ")?
+                .split_terminator(", ")
+                .map(|x| source.number(x))
+                .collect::<Result<_, _>>()?;
+            let (op, value) = source.split_once(line(2, "  Operation: new = old ")?, " ")?;
+            let operation = match (op, value) {
+                ("*", "old") => Op::Square,
+                ("*", _) => Op::Multiply(source.number(value)?),
+                ("+", _) => Op::Add(source.number(value)?),
+                _ => return Err(source.error(op, "expected `*` or `+`")),
+            };
+            let divisible_by_str = line(3, "  Test: divisible by ")?;
+            let divisible_by = match source.number(divisible_by_str)? {
+                0 => return Err(source.error(divisible_by_str, "cannot divide by zero")),
+                divisible_by => divisible_by,
+            };
+            let true_target = line(4, "    If true: throw to monkey ")?;
+            let false_target = line(5, "    If false: throw to monkey ")?;
+            Ok((
+                Monkey {
+                    items,
+                    operation,
+                    divisible_by,
+                    true_target: source.number(true_target)?,
+                    false_target: source.number(false_target)?,
+                    inspected_items: 0,
+                },
+                [true_target, false_target],
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for (monkey, texts) in &monkeys {
+        for (target, text) in [monkey.true_target, monkey.false_target].iter().zip(texts) {
+            if *target >= monkeys.len() {
+                return Err(source.error(text, "there is no such monkey"));
+            }
+        }
+    }
+    Ok(Game {
+        monkeys: monkeys.into_iter().map(|(monkey, _)| monkey).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, Day11};
+    use aoc_common::{example::assert_examples, fixture};
+
+    #[test]
+    fn part1_test() {
+        let mut world = parse(fixture!("test-input")).unwrap();
+        world.play_n_rounds(20, true);
+        assert_eq!(world.score(), 10605);
+    }
+
+    #[test]
+    fn part2_test() {
+        let mut world = parse(fixture!("test-input")).unwrap();
+        world.play_n_rounds(10000, false);
+        assert_eq!(world.score(), 2713310158);
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day11>();
+    }
+}
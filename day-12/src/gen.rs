@@ -0,0 +1,50 @@
+//! A `size` wide heightmap, with a climbable ridge from `S` to `E` among random heights.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 30;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(MIN_SIZE);
+    let height = width / 2;
+    let mut map = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // the ridge climbs at most one level per step, resting on a few plateaus on the way
+    let y = rng.gen_range(0..height);
+    let mut plateaus = rng.gen_range(0..=width - 28);
+    let (mut x, mut level) = (1, b'a');
+    map[y][0] = 'S';
+    map[y][1] = 'a';
+    while level < b'z' {
+        x += 1;
+        if plateaus > 0 && rng.gen_bool(0.5) {
+            plateaus -= 1;
+        } else {
+            level += 1;
+        }
+        map[y][x] = level as char;
+    }
+    map[y][x + 1] = 'E';
+
+    map.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day12>(generate, 60);
+    }
+}
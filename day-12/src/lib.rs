@@ -0,0 +1,139 @@
+pub mod gen;
+
+use aoc_common::{fixture, search, Example, Grid, MaybeAnswer, ParseError, Solution, Source};
+use std::fmt::Display;
+
+pub struct Day12;
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("31")
+        .part2("29")];
+
+    type Input = HillMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(hill_map: &Self::Input) -> impl Display {
+        MaybeAnswer(shortest_path(&hill_map.map, hill_map.start, hill_map.top))
+    }
+
+    fn part2(hill_map: &Self::Input) -> impl Display {
+        MaybeAnswer(shortest_path_for_all_starts(&hill_map.map, hill_map.top))
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    name: char,
+    neighbours: Vec<((usize, usize), usize)>,
+}
+
+fn shortest_path_for_all_starts(map: &Grid<Node>, target: (usize, usize)) -> Option<usize> {
+    let starts = find_all_starting_positions(map, &['S', 'a']);
+    search::dijkstra(
+        starts,
+        |&pos| map[pos].neighbours.iter().copied(),
+        |&pos| pos == target,
+    )
+    .map(|path| path.cost)
+}
+
+fn shortest_path(map: &Grid<Node>, start: (usize, usize), goal: (usize, usize)) -> Option<usize> {
+    search::dijkstra(
+        [start],
+        |&pos| map[pos].neighbours.iter().copied(),
+        |&pos| pos == goal,
+    )
+    .map(|path| path.cost)
+}
+
+fn find_all_starting_positions(map: &Grid<Node>, target: &[char]) -> Vec<(usize, usize)> {
+    map.iter()
+        .filter_map(|(pos, node)| target.contains(&node.name).then_some(pos))
+        .collect()
+}
+
+fn find_neighbours(map: &Grid<char>, pos: (usize, usize)) -> Vec<((usize, usize), usize)> {
+    let curr = map[pos];
+    map.neighbours4(pos)
+        .filter(|&next_pos| {
+            let next = map[next_pos];
+            curr != 'S'
+                && (next == 'E' && curr == 'z' || next != 'E' && next as isize - curr as isize <= 1)
+                || curr == 'S' && next == 'a'
+        })
+        .map(|next_pos| (next_pos, 1))
+        .collect()
+}
+
+pub struct HillMap {
+    map: Grid<Node>,
+    start: (usize, usize),
+    top: (usize, usize),
+}
+
+fn parse(input: &str) -> Result<HillMap, ParseError> {
+    let source = Source::new(input);
+    let map = Grid::parse(input, |ch| {
+        matches!(ch, 'a'..='z' | 'S' | 'E').then_some(ch)
+    })?;
+    let start = map
+        .position(|&ch| ch == 'S')
+        .ok_or_else(|| source.eof("missing the start `S`"))?;
+    let end = map
+        .position(|&ch| ch == 'E')
+        .ok_or_else(|| source.eof("missing the top `E`"))?;
+
+    let nodes_map = Grid::from_vec(
+        map.width(),
+        map.iter()
+            .map(|(pos, &name)| Node {
+                name,
+                neighbours: find_neighbours(&map, pos),
+            })
+            .collect(),
+    );
+    Ok(HillMap {
+        map: nodes_map,
+        start,
+        top: end,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, shortest_path, shortest_path_for_all_starts, Day12};
+    use aoc_common::{example::assert_examples, fixture, Solution};
+
+    #[test]
+    fn part1_test() {
+        let hill_map = parse(fixture!("test-input")).unwrap();
+        assert_eq!(
+            shortest_path(&hill_map.map, hill_map.start, hill_map.top),
+            Some(31)
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let hill_map = parse(fixture!("test-input")).unwrap();
+        assert_eq!(
+            shortest_path_for_all_starts(&hill_map.map, hill_map.top),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn unreachable_test() {
+        // `b` is too high to climb from `S`, which stands for `a`
+        assert_eq!(Day12::solve("SbE\n").unwrap(), ["None", "None"]);
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day12>();
+    }
+}
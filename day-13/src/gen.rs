@@ -0,0 +1,34 @@
+//! `size` pairs of packets, nested up to four lists deep.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let pairs = (0..size)
+        .map(|_| format!("{}\n{}\n", packet(rng, 4), packet(rng, 4)))
+        .collect::<Vec<_>>();
+    pairs.join("\n")
+}
+
+fn packet(rng: &mut StdRng, depth: usize) -> String {
+    let items = (0..rng.gen_range(0..=5))
+        .map(|_| match depth > 1 && rng.gen_bool(0.3) {
+            true => packet(rng, depth - 1),
+            false => rng.gen_range(0..=10).to_string(),
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day13>(generate, 100);
+    }
+}
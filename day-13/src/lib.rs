@@ -0,0 +1,256 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+pub struct Day13;
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("13")
+        .part2("140")];
+
+    type Input = Vec<(Type, Type)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> impl Display {
+        sum_pos_of_ordered(pairs)
+    }
+
+    fn part2(pairs: &Self::Input) -> impl Display {
+        find_decoder_key(pairs.clone()).expect("divider packets not found")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Type {
+    Vec(Vec<Type>),
+    Int(isize),
+}
+impl PartialEq for Type {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.cmp(other), Ordering::Equal)
+    }
+}
+impl PartialOrd for Type {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Eq for Type {}
+impl Ord for Type {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Type::Vec(l), Type::Vec(r)) => {
+                if r.is_empty() && l.len() > r.len() {
+                    return Ordering::Greater;
+                } else if l.is_empty() && l.len() < r.len() {
+                    return Ordering::Less;
+                }
+                let mut l_iter = l.iter();
+                let mut r_iter = r.iter();
+                loop {
+                    match (l_iter.next(), r_iter.next()) {
+                        (Some(l), Some(r)) => match l.cmp(r) {
+                            Ordering::Equal => continue,
+                            ordering => return ordering,
+                        },
+                        (Some(_), None) => return Ordering::Greater,
+                        (None, Some(_)) => return Ordering::Less,
+                        (None, None) => return Ordering::Equal,
+                    }
+                }
+            }
+            (Type::Int(l), Type::Vec(r)) => {
+                Type::Vec(vec![Type::Int(*l)]).cmp(&Type::Vec(r.to_vec()))
+            }
+            (Type::Vec(l), Type::Int(r)) => {
+                Type::Vec(l.to_vec()).cmp(&Type::Vec(vec![Type::Int(*r)]))
+            }
+            (Type::Int(l), Type::Int(r)) => l.cmp(r),
+        }
+    }
+}
+
+/// Writes the packet the way it appears in the input, e.g. `[1,[2,3]]`.
+impl Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Type::Int(value) => write!(f, "{value}"),
+            Type::Vec(items) => {
+                write!(f, "[")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+fn sum_pos_of_ordered(pairs: &[(Type, Type)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (l, r))| if l < r { Some(i + 1) } else { None })
+        .sum()
+}
+
+fn find_decoder_key(pairs: Vec<(Type, Type)>) -> Option<usize> {
+    let mut packets = pairs
+        .into_iter()
+        .flat_map(|(l, r)| vec![l, r])
+        .collect::<Vec<_>>();
+    let divider1 = Type::Vec(vec![Type::Vec(vec![Type::Int(2)])]);
+    let divider2 = Type::Vec(vec![Type::Vec(vec![Type::Int(6)])]);
+    packets.push(divider1.clone());
+    packets.push(divider2.clone());
+    packets.sort();
+    let pos1 = packets.iter().position(|p| p == &divider1)?;
+    let pos2 = packets.iter().position(|p| p == &divider2)?;
+    Some((pos1 + 1) * (pos2 + 1))
+}
+
+/// Parses the packet starting at byte `pos` of `line`, leaving `pos` right after it.
+fn parse_packet(source: &Source, line: &str, pos: &mut usize) -> Result<Type, ParseError> {
+    let rest = &line[*pos..];
+    if rest.starts_with('[') {
+        *pos += 1;
+        let mut vec = Vec::new();
+        if line[*pos..].starts_with(']') {
+            *pos += 1;
+            return Ok(Type::Vec(vec));
+        }
+        loop {
+            vec.push(parse_packet(source, line, pos)?);
+            match line[*pos..].chars().next() {
+                Some(',') => *pos += 1,
+                Some(']') => {
+                    *pos += 1;
+                    return Ok(Type::Vec(vec));
+                }
+                _ => return Err(unexpected(source, line, *pos, "expected `,` or `]`")),
+            }
+        }
+    }
+    let digits = rest.len()
+        - rest
+            .trim_start_matches(|ch: char| ch.is_ascii_digit())
+            .len();
+    if digits == 0 {
+        return Err(unexpected(source, line, *pos, "expected `[` or a number"));
+    }
+    *pos += digits;
+    Ok(Type::Int(source.number(&rest[..digits])?))
+}
+
+fn unexpected(source: &Source, line: &str, pos: usize, reason: &str) -> ParseError {
+    let rest = &line[pos..];
+    let len = rest.chars().next().map_or(0, char::len_utf8);
+    source.error(&rest[..len], reason)
+}
+
+fn parse_line(source: &Source, line: &str) -> Result<Type, ParseError> {
+    let mut pos = 0;
+    let packet = parse_packet(source, line, &mut pos)?;
+    if pos < line.len() {
+        return Err(source.error(&line[pos..], "unexpected text after the packet"));
+    }
+    Ok(packet)
+}
+
+fn parse(content: &str) -> Result<Vec<(Type, Type)>, ParseError> {
+    let source = Source::new(content);
+    content
+        .split_terminator("\n\n")
+        .map(|pair| {
+            let (left, right) = source.split_once(pair, "\n")?;
+            Ok((
+                parse_line(&source, left.trim())?,
+                parse_line(&source, right.trim())?,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+    use proptest::prelude::*;
+
+    /// Small numbers and short lists, so that packets often compare equal on some prefix.
+    fn packet() -> impl Strategy<Value = Type> {
+        let item = (0..4isize)
+            .prop_map(Type::Int)
+            .prop_recursive(3, 16, 3, |item| {
+                prop::collection::vec(item, 0..3).prop_map(Type::Vec)
+            });
+        prop::collection::vec(item, 0..4).prop_map(Type::Vec)
+    }
+
+    #[test]
+    fn part1_test() {
+        let pairs = parse(fixture!("test-input")).unwrap();
+        assert_eq!(sum_pos_of_ordered(&pairs), 13);
+    }
+
+    #[test]
+    fn part2_test() {
+        let pairs = parse(fixture!("test-input")).unwrap();
+        assert_eq!(find_decoder_key(pairs), Some(140));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("[1,[2]]\n[3;4]\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, ";"));
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day13>();
+    }
+
+    proptest! {
+        #[test]
+        fn antisymmetric_test(a in packet(), b in packet()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        }
+
+        #[test]
+        fn transitive_test(a in packet(), b in packet(), c in packet()) {
+            // some ordering of the three is increasing whenever the order is total
+            let orderings = [
+                (&a, &b, &c),
+                (&a, &c, &b),
+                (&b, &a, &c),
+                (&b, &c, &a),
+                (&c, &a, &b),
+                (&c, &b, &a),
+            ];
+            for (x, y, z) in orderings {
+                if x <= y && y <= z {
+                    prop_assert!(x <= z, "{x} <= {y} <= {z}");
+                }
+            }
+        }
+
+        #[test]
+        fn round_trip_test(packet in packet()) {
+            let printed = packet.to_string();
+            let parsed = parse_line(&Source::new(&printed), &printed).unwrap();
+            prop_assert_eq!(parsed.to_string(), printed);
+            prop_assert_eq!(parsed.cmp(&packet), Ordering::Equal);
+        }
+    }
+}
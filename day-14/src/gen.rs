@@ -0,0 +1,45 @@
+//! `size` paths of rock below the source of the sand.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let spread = 10 + size as isize / 2;
+    (0..size)
+        .map(|_| {
+            let mut point = (
+                rng.gen_range(500 - spread..=500 + spread),
+                rng.gen_range(2..=2 + spread),
+            );
+            let mut points = vec![point];
+            let mut horizontal = rng.gen_bool(0.5);
+            for _ in 0..rng.gen_range(1..=4) {
+                let length = rng.gen_range(1..=8) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                match horizontal {
+                    true => point.0 += length,
+                    false => point.1 = (point.1 + length).max(2),
+                }
+                points.push(point);
+                horizontal = !horizontal;
+            }
+            let points = points
+                .into_iter()
+                .map(|(x, y)| format!("{x},{y}"))
+                .collect::<Vec<_>>();
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day14>(generate, 50);
+    }
+}
@@ -0,0 +1,189 @@
+pub mod gen;
+
+use aoc_common::{animation::Render, fixture, Example, Grid, ParseError, Solution, Source};
+use std::{collections::HashMap, fmt::Display};
+
+pub struct Day14;
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("24")
+        .part2("93")];
+
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cave: &Self::Input) -> impl Display {
+        let mut cave = cave.clone();
+        cave.simulate_til_end();
+        cave.count_sand()
+    }
+
+    fn part2(cave: &Self::Input) -> impl Display {
+        let mut cave = cave.clone();
+        cave.set_y_limit();
+        cave.simulate_til_end();
+        cave.count_sand()
+    }
+}
+
+const SAND_SOURCE: (isize, isize) = (500, 0);
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    map: HashMap<(isize, isize), char>,
+    min: (isize, isize),
+    max: (isize, isize),
+    has_floor: bool,
+}
+impl Cave {
+    fn simulate_til_end(&mut self) {
+        while self.simulate_drop().is_some() {}
+    }
+
+    fn simulate_drop(&mut self) -> Option<(isize, isize)> {
+        let mut curr_pos = (SAND_SOURCE.0, SAND_SOURCE.1);
+        while curr_pos.1 < self.max.1 {
+            let next_y = curr_pos.1 + 1;
+            let is_floor_level = self.has_floor && next_y == self.max.1;
+            let is_used_bellow = self.map.contains_key(&(curr_pos.0, next_y)) || is_floor_level;
+            let is_used_bellow_left =
+                self.map.contains_key(&(curr_pos.0 - 1, next_y)) || is_floor_level;
+            let is_used_bellow_right =
+                self.map.contains_key(&(curr_pos.0 + 1, next_y)) || is_floor_level;
+            match (is_used_bellow, is_used_bellow_left, is_used_bellow_right) {
+                (true, true, true) if self.map.contains_key(&curr_pos) => {
+                    return None;
+                }
+                (true, true, true) if !self.map.contains_key(&curr_pos) => {
+                    self.add_item(curr_pos, 'o');
+                    return Some(curr_pos);
+                }
+                (true, false, _) => curr_pos.0 -= 1,
+                (true, true, false) => curr_pos.0 += 1,
+                _ => curr_pos.1 = next_y,
+            }
+        }
+        None
+    }
+
+    fn add_item(&mut self, (x, y): (isize, isize), ch: char) {
+        self.map.insert((x, y), ch);
+        self.min = (x.min(self.min.0), y.min(self.min.1));
+        self.max = (x.max(self.max.0), y.max(self.max.1));
+    }
+
+    fn count_sand(&self) -> usize {
+        self.map.iter().filter(|(_, &ch)| ch == 'o').count()
+    }
+
+    fn set_y_limit(&mut self) {
+        self.max.1 += 2;
+        self.has_floor = true;
+    }
+}
+impl Render for Cave {
+    fn render(&self) -> String {
+        let (w, h) = (
+            (self.max.0 - self.min.0 + 1) as usize,
+            (self.max.1 + 1) as usize,
+        );
+        let mut map = Grid::new(w, h, '.');
+        map[(0, (SAND_SOURCE.0 - self.min.0) as usize)] = '+';
+        for (&(x, y), &ch) in self.map.iter() {
+            map[(y as usize, (x - self.min.0) as usize)] = ch;
+        }
+        map.to_string()
+    }
+
+    /// Drops one unit of sand, until they start falling into the abyss.
+    fn step(&mut self) -> bool {
+        self.simulate_drop().is_some()
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Render>, ParseError> {
+    Ok(Box::new(parse(input)?))
+}
+
+fn parse(input: &str) -> Result<Cave, ParseError> {
+    let source = Source::new(input);
+    let mut cave = Cave {
+        map: HashMap::new(),
+        min: (isize::MAX, isize::MAX),
+        max: (isize::MIN, isize::MIN),
+        has_floor: false,
+    };
+    for l in input.lines() {
+        let line_points = l
+            .split_terminator(" -> ")
+            .map(|s| {
+                let (x, y) = source.split_once(s, ",")?;
+                Ok((s, (source.number::<isize>(x)?, source.number::<isize>(y)?)))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        for points in line_points.windows(2) {
+            let ((_, from), (text, to)) = (points[0], points[1]);
+            if from.0 != to.0 && from.1 != to.1 {
+                return Err(source.error(text, "rock paths must be horizontal or vertical"));
+            }
+            for x in from.0.min(to.0)..=from.0.max(to.0) {
+                for y in from.1.min(to.1)..=from.1.max(to.1) {
+                    cave.add_item((x, y), '#');
+                }
+            }
+        }
+    }
+    Ok(cave)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+
+    #[test]
+    fn part1_test() {
+        let mut cave = parse(fixture!("test-input")).unwrap();
+        cave.simulate_til_end();
+        assert_eq!(cave.count_sand(), 24);
+    }
+
+    #[test]
+    fn part2_test() {
+        let mut cave = parse(fixture!("test-input")).unwrap();
+        cave.set_y_limit();
+        cave.simulate_til_end();
+        assert_eq!(cave.count_sand(), 93);
+    }
+
+    #[test]
+    fn render_test() {
+        let mut cave = parse(fixture!("test-input")).unwrap();
+        for _ in 0..5 {
+            assert!(cave.step());
+        }
+        assert_eq!(
+            cave.render(),
+            "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+......o.#.
+....oooo#.
+#########."
+        );
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day14>();
+    }
+}
@@ -0,0 +1,83 @@
+//! `size` sensors around a distress beacon, which is the only position they leave uncovered in
+//! the search area of part 2.
+//!
+//! Eight huge sensors surround the distress beacon just out of its reach, together covering the
+//! whole search area but the beacon. The other sensors are random, and never reach the beacon.
+
+use aoc_common::{
+    gen::{Rng, StdRng},
+    Point2,
+};
+
+pub const MIN_SIZE: usize = 0;
+
+const LIMIT: isize = 4_000_000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let (_, sensors) = sensors(rng, size);
+    sensors
+        .into_iter()
+        .map(|(sensor, beacon)| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.x, sensor.y, beacon.x, beacon.y
+            )
+        })
+        .collect()
+}
+
+/// The distress beacon, and every sensor with its closest beacon.
+fn sensors(rng: &mut StdRng, size: usize) -> (Point2, Vec<(Point2, Point2)>) {
+    let distress = Point2::new(rng.gen_range(0..=LIMIT), rng.gen_range(0..=LIMIT));
+    let mut sensors = Vec::new();
+    // each covers the quarter of the plane towards it, but its border
+    let reach = 3 * LIMIT;
+    for dir in [(0, -1), (1, 0), (0, 1), (-1, 0)].map(Point2::from) {
+        sensors.push((distress + dir * (reach + 1), distress + dir));
+    }
+    // each covers one of the borders left, up to the distress beacon
+    let reach = 2 * LIMIT;
+    for dir in [(1, -1), (1, 1), (-1, 1), (-1, -1)].map(Point2::from) {
+        let sensor = distress + dir * reach;
+        sensors.push((sensor, sensor - Point2::new(dir.x, 0) * (2 * reach - 1)));
+    }
+    while sensors.len() < size {
+        let sensor = Point2::new(rng.gen_range(0..=LIMIT), rng.gen_range(0..=LIMIT));
+        if sensor == distress {
+            continue;
+        }
+        let reach = rng.gen_range(0..sensor.manhattan_distance(distress));
+        let dx = rng.gen_range(-reach..=reach);
+        let dy = (reach - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+        sensors.push((sensor, sensor + Point2::new(dx, dy)));
+    }
+    (distress, sensors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use aoc_common::gen::rng;
+
+    /// Scanning the whole search area takes too long for a test, only the rows around the distress
+    /// beacon are checked.
+    #[test]
+    fn generate_test() {
+        for seed in 0..3 {
+            let (distress, _) = sensors(&mut rng(seed), 20);
+            let world = parse(&generate(&mut rng(seed), 20)).unwrap();
+            let gaps = |y| {
+                world
+                    .coverage(y)
+                    .complement(0..=LIMIT as i64)
+                    .ranges()
+                    .collect::<Vec<_>>()
+            };
+            let x = distress.x as i64;
+            assert_eq!(gaps(distress.y), [x..=x]);
+            assert_eq!(gaps(distress.y - 1), []);
+            assert_eq!(gaps(distress.y + 1), []);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+pub mod gen;
+
+use aoc_common::{IntervalSet, MaybeAnswer, ParseError, Point2, Solution, Source};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+pub struct Day15;
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(world: &Self::Input) -> impl Display {
+        world.count_unavailable_for_y(2000000)
+    }
+
+    fn part2(world: &Self::Input) -> impl Display {
+        MaybeAnswer(world.beacon_frequency(4_000_000))
+    }
+}
+
+#[derive(Debug)]
+pub struct World {
+    sensors: Vec<Sensor>,
+    map: HashMap<Point2, char>,
+}
+impl World {
+    fn new() -> Self {
+        Self {
+            sensors: Vec::new(),
+            map: HashMap::new(),
+        }
+    }
+
+    fn beacon_frequency(&self, limit: isize) -> Option<u128> {
+        (0..=limit).find_map(|y| {
+            let x = self
+                .coverage(y)
+                .complement(0..=limit as i64)
+                .ranges()
+                .next()?;
+            Some(*x.start() as u128 * 4_000_000 + y as u128)
+        })
+    }
+
+    fn count_unavailable_for_y(&self, y: isize) -> u64 {
+        let coverage = self.coverage(y);
+        let beacons = self
+            .sensors
+            .iter()
+            .map(|sensor| sensor.closest_beacon)
+            .filter(|beacon| beacon.y == y && coverage.contains(beacon.x as i64))
+            .collect::<HashSet<_>>();
+        coverage.len() - beacons.len() as u64
+    }
+
+    /// Positions of row `y` that are closer to a sensor than its closest beacon, or as close.
+    fn coverage(&self, y: isize) -> IntervalSet {
+        self.sensors
+            .iter()
+            .map(|sensor| {
+                let dist = sensor.pos.manhattan_distance(sensor.closest_beacon);
+                let x_diff = (dist - (sensor.pos.y - y).abs()) as i64;
+                sensor.pos.x as i64 - x_diff..=sensor.pos.x as i64 + x_diff
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+struct Sensor {
+    pos: Point2,
+    closest_beacon: Point2,
+}
+
+fn parse(input: &str) -> Result<World, ParseError> {
+    let source = Source::new(input);
+    let re = regex::Regex::new(
+        r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$",
+    )
+    .unwrap();
+    let mut world = World::new();
+    for line in input.lines() {
+        let captures = re.captures(line).ok_or_else(|| {
+            source.error(
+                line,
+                "expected `Sensor at x=X, y=Y: closest beacon is at x=X, y=Y`",
+            )
+        })?;
+        let values = captures
+            .iter()
+            .skip(1)
+            .map(|cap| source.number::<isize>(cap.unwrap().as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let beacon = Point2::new(values[2], values[3]);
+        let sensor = Sensor {
+            pos: Point2::new(values[0], values[1]),
+            closest_beacon: beacon,
+        };
+        world.sensors.push(sensor);
+        world.map.insert(sensor.pos, 'S');
+        world.map.insert(beacon, 'B');
+    }
+    Ok(world)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixture;
+    use proptest::prelude::*;
+
+    /// Sensors close to the origin, each with its beacon at most 5 positions away on both axes.
+    fn world() -> impl Strategy<Value = World> {
+        prop::collection::vec((-10..10isize, -10..10isize, -5..=5isize, -5..=5isize), 1..5)
+            .prop_map(|sensors| {
+                let mut world = World::new();
+                for (x, y, dx, dy) in sensors {
+                    let pos = Point2::new(x, y);
+                    let closest_beacon = Point2::new(x + dx, y + dy);
+                    world.sensors.push(Sensor {
+                        pos,
+                        closest_beacon,
+                    });
+                    world.map.insert(pos, 'S');
+                    world.map.insert(closest_beacon, 'B');
+                }
+                world
+            })
+    }
+
+    fn covered(world: &World, pos: Point2) -> bool {
+        world.sensors.iter().any(|sensor| {
+            sensor.pos.manhattan_distance(pos)
+                <= sensor.pos.manhattan_distance(sensor.closest_beacon)
+        })
+    }
+
+    #[test]
+    fn part1_test() {
+        let world = parse(fixture!("test-input")).unwrap();
+        assert_eq!(world.count_unavailable_for_y(10), 26);
+    }
+
+    #[test]
+    fn part2_test() {
+        let world = parse(fixture!("test-input")).unwrap();
+        assert_eq!(world.beacon_frequency(20), Some(56000011));
+    }
+
+    #[test]
+    fn no_beacon_test() {
+        let input = "Sensor at x=2000000, y=2000000: closest beacon is at x=6000000, y=2000000\n";
+        assert_eq!(Day15::solve(input).unwrap()[1], "None");
+    }
+
+    proptest! {
+        #[test]
+        fn unavailable_brute_force_test(world in world(), y in -15..15isize) {
+            let beacons = world
+                .sensors
+                .iter()
+                .map(|sensor| sensor.closest_beacon)
+                .collect::<HashSet<_>>();
+            let expected = (-40..40)
+                .map(|x| Point2::new(x, y))
+                .filter(|&pos| covered(&world, pos) && !beacons.contains(&pos))
+                .count();
+            prop_assert_eq!(world.count_unavailable_for_y(y), expected as u64);
+        }
+
+        #[test]
+        fn frequency_brute_force_test(world in world(), limit in 0..12isize) {
+            let expected = (0..=limit)
+                .flat_map(|y| (0..=limit).map(move |x| Point2::new(x, y)))
+                .find(|&pos| !covered(&world, pos))
+                .map(|pos| pos.x as u128 * 4_000_000 + pos.y as u128);
+            prop_assert_eq!(world.beacon_frequency(limit), expected);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! `size` valves connected by tunnels, a quarter of them with a flow rate but never more than 15.
+
+use aoc_common::gen::{Rng, SliceRandom, StdRng};
+use std::collections::BTreeSet;
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names = ('A'..='Z')
+        .flat_map(|first| ('A'..='Z').map(move |second| format!("{first}{second}")))
+        .filter(|name| name != "AA")
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+    names.truncate(count - 1);
+    names.insert(0, "AA".into());
+
+    // a random tree keeps every valve reachable, a few more tunnels add loops
+    let mut tunnels = vec![BTreeSet::new(); count];
+    let mut connect = |from: usize, to: usize| {
+        if from != to {
+            tunnels[from].insert(to);
+            tunnels[to].insert(from);
+        }
+    };
+    for idx in 1..count {
+        connect(idx, rng.gen_range(0..idx));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+
+    let mut flow_rates = vec![0; count];
+    let mut valued = (1..count).collect::<Vec<_>>();
+    valued.shuffle(rng);
+    for &idx in valued.iter().take((count / 4).clamp(1, 15)) {
+        flow_rates[idx] = rng.gen_range(1..=25);
+    }
+
+    let mut lines = (0..count)
+        .map(|idx| {
+            let leads_to = tunnels[idx]
+                .iter()
+                .map(|&to| names[to].as_str())
+                .collect::<Vec<_>>();
+            let tunnels = match leads_to.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels} {}\n",
+                names[idx],
+                flow_rates[idx],
+                leads_to.join(", ")
+            )
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day16;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day16>(generate, 12);
+    }
+}
@@ -0,0 +1,207 @@
+pub mod gen;
+
+use aoc_common::{fixture, search, Example, ParseError, Solution, Source};
+use std::{cmp::Reverse, collections::HashMap, fmt::Display, rc::Rc};
+
+pub struct Day16;
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("1651")
+        .part2("1707")];
+
+    type Input = Valves;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(valves: &Self::Input) -> impl Display {
+        calc_max_pressure_release(valves)
+    }
+
+    fn part2(valves: &Self::Input) -> impl Display {
+        calc_max_pressure_release2(valves)
+    }
+}
+
+pub type Valves = HashMap<Rc<String>, (usize, Vec<Rc<String>>)>;
+
+fn calc_max_pressure_release(valves: &Valves) -> usize {
+    released_by_opened(valves, 30)
+        .into_values()
+        .max()
+        .unwrap_or(0)
+}
+
+fn calc_max_pressure_release2(valves: &Valves) -> usize {
+    // the elephant and I open valves the other doesn't, so the best two disjoint sets win
+    let mut released = released_by_opened(valves, 26)
+        .into_iter()
+        .collect::<Vec<_>>();
+    released.sort_by_key(|&(_, pressure)| Reverse(pressure));
+    let mut max = 0;
+    for (idx, &(mine, my_pressure)) in released.iter().enumerate() {
+        for &(elephants, elephant_pressure) in &released[idx..] {
+            if my_pressure + elephant_pressure <= max {
+                break;
+            }
+            if mine & elephants == 0 {
+                max = my_pressure + elephant_pressure;
+            }
+        }
+    }
+    max
+}
+
+/// Most pressure released in `minutes` from `AA` for every set of valves that can be opened in
+/// time, as a bitmask over the valves worth opening.
+fn released_by_opened(valves: &Valves, minutes: usize) -> HashMap<u64, usize> {
+    let dist = get_distance_between_valued_nodes(valves);
+    let worth = dist
+        .iter()
+        .filter(|(_, (flow_rate, _))| *flow_rate > 0)
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let start = dist.keys().find(|name| name.as_str() == "AA").unwrap();
+    let mut released = HashMap::new();
+    let mut stack = vec![(start.clone(), minutes, 0u64, 0)];
+    while let Some((valve, remaining_time, opened, pressure)) = stack.pop() {
+        let best = released.entry(opened).or_insert(0);
+        *best = pressure.max(*best);
+        for (bit, next) in worth.iter().enumerate() {
+            let Some(&steps) = dist[&valve].1.get(next) else {
+                continue;
+            };
+            // walking there and opening it must leave a minute for it to release anything
+            if opened & 1 << bit == 0 && steps + 1 < remaining_time {
+                let remaining_time = remaining_time - steps - 1;
+                stack.push((
+                    next.clone(),
+                    remaining_time,
+                    opened | 1 << bit,
+                    pressure + dist[next].0 * remaining_time,
+                ));
+            }
+        }
+    }
+    released
+}
+
+type Distances = HashMap<Rc<String>, (usize, HashMap<Rc<String>, usize>)>;
+
+/// Flow rate of `AA` and of every valve worth opening, with the minutes it takes to walk from
+/// it to each of the others.
+fn get_distance_between_valued_nodes(valves: &Valves) -> Distances {
+    let tracked = |name: &Rc<String>| valves[name].0 > 0 || name.starts_with("AA");
+    valves
+        .iter()
+        .filter(|(name, _)| tracked(name))
+        .map(|(name, &(flow_rate, _))| {
+            let mut dist = search::distances([name.clone()], |valve| {
+                valves[valve].1.iter().map(|next| (next.clone(), 1))
+            });
+            dist.retain(|other, _| tracked(other));
+            (name.clone(), (flow_rate, dist))
+        })
+        .collect()
+}
+
+fn parse(input: &str) -> Result<Valves, ParseError> {
+    let source = Source::new(input);
+    let re = regex::Regex::new(
+        r#"^Valve ([A-Z]{2}) has flow rate=(\d+); tunnels? leads? to valves? (.*)$"#,
+    )
+    .unwrap();
+    let valves = input
+        .trim()
+        .lines()
+        .map(|l| {
+            let caps = re.captures(l).ok_or_else(|| {
+                source.error(
+                    l,
+                    "expected `Valve XX has flow rate=N; tunnels lead to valves YY, ZZ`",
+                )
+            })?;
+            let tunnels = caps
+                .get(3)
+                .unwrap()
+                .as_str()
+                .trim()
+                .split_terminator(',')
+                .map(str::trim)
+                .collect::<Vec<_>>();
+            Ok((
+                caps.get(1).unwrap().as_str(),
+                source.number(caps.get(2).unwrap().as_str())?,
+                tunnels,
+            ))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    if let Some(tunnel) = valves
+        .iter()
+        .flat_map(|(_, _, tunnels)| tunnels)
+        .find(|&&tunnel| !valves.iter().any(|(name, _, _)| *name == tunnel))
+    {
+        return Err(source.error(tunnel, "no such valve"));
+    }
+    if !valves.iter().any(|(name, _, _)| *name == "AA") {
+        return Err(source.eof("missing the starting valve `AA`"));
+    }
+    // the valves opened so far are a bitmask
+    if let Some((name, _, _)) = valves
+        .iter()
+        .filter(|(_, flow_rate, _)| *flow_rate > 0)
+        .nth(64)
+    {
+        return Err(source.error(name, "more than 64 valves have a flow rate"));
+    }
+    Ok(valves
+        .into_iter()
+        .map(|(name, flow_rate, tunnels)| {
+            (
+                Rc::new(name.into()),
+                (
+                    flow_rate,
+                    tunnels.into_iter().map(|s| Rc::new(s.into())).collect(),
+                ),
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+
+    #[test]
+    fn part1_test() {
+        let valves = parse(fixture!("test-input")).unwrap();
+        assert_eq!(calc_max_pressure_release(&valves), 1651);
+    }
+
+    #[test]
+    fn part2_test() {
+        let valves = parse(fixture!("test-input")).unwrap();
+        assert_eq!(calc_max_pressure_release2(&valves), 1707);
+    }
+
+    #[test]
+    fn distances_test() {
+        let valves = parse(fixture!("test-input")).unwrap();
+        let dist = get_distance_between_valued_nodes(&valves);
+        assert_eq!(dist.len(), 7);
+        let (flow_rate, from_start) = &dist[&Rc::new("AA".to_string())];
+        assert_eq!(*flow_rate, 0);
+        assert_eq!(from_start.len(), 7);
+        assert_eq!(from_start[&Rc::new("DD".to_string())], 1);
+        assert_eq!(from_start[&Rc::new("JJ".to_string())], 2);
+        assert_eq!(from_start[&Rc::new("HH".to_string())], 5);
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day16>();
+    }
+}
@@ -0,0 +1,54 @@
+//! A jet pattern of `size` pushes, at least 10.
+//!
+//! Random jets often keep the rocks away from a column, leaving a well down to the floor that
+//! grows with every rock, where the real inputs fill the whole chamber. The solution floods that
+//! well after every rock and never finds a cycle in it, so patterns are drawn again until the
+//! rocks of part 1 leave none. After [`MAX_TRIES`] patterns the last one is kept, part 2 may then
+//! not finish on it, see `well_test` in the solution.
+
+use crate::Tetris;
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 10;
+
+/// Patterns drawn before keeping one with a well anyway, the smallest sizes need about 10.
+const MAX_TRIES: usize = 100;
+
+/// Most rows still reachable from the top while a pattern is tried, the real inputs need about
+/// 80.
+const MAX_DEPTH: usize = 128;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut jets = String::new();
+    for _ in 0..MAX_TRIES {
+        jets = (0..size.max(MIN_SIZE))
+            .map(|_| if rng.gen_bool(0.5) { '<' } else { '>' })
+            .collect::<String>();
+        if leaves_no_well(&jets) {
+            break;
+        }
+    }
+    jets + "\n"
+}
+
+fn leaves_no_well(jets: &str) -> bool {
+    let mut tetris = Tetris::new(jets);
+    (0..2022).all(|_| {
+        tetris.place_shape();
+        tetris.map.height() <= MAX_DEPTH
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        for size in [0, 7, 60] {
+            assert_generates::<Day17>(generate, size);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! `size` distinct cubes packed in a box about twice their volume, which leaves air pockets.
+
+use aoc_common::{
+    gen::{Rng, StdRng},
+    Point3,
+};
+use std::collections::HashSet;
+
+pub const MIN_SIZE: usize = 0;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let side = ((2 * size) as f64).cbrt().ceil() as isize;
+    let mut cubes = HashSet::new();
+    let mut input = String::new();
+    while cubes.len() < size {
+        let [x, y, z] = [(); 3].map(|_| rng.gen_range(0..side));
+        if cubes.insert(Point3::new(x, y, z)) {
+            input += &format!("{x},{y},{z}\n");
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day18>(generate, 200);
+    }
+}
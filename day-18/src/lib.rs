@@ -0,0 +1,198 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Point3, Solution, Source};
+use itertools::*;
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day18;
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("64")
+        .part2("58")];
+
+    type Input = Vec<Point3>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cubes: &Self::Input) -> impl Display {
+        count_surface_area(cubes)
+    }
+
+    fn part2(cubes: &Self::Input) -> impl Display {
+        count_exterior_surface_area(cubes)
+    }
+}
+
+fn count_surface_area(cubes: &[Point3]) -> usize {
+    let groups = group_cubes(cubes);
+
+    groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .map(|item| {
+                    item.neighbours6()
+                        .filter(|neighbour| !group.contains(neighbour))
+                        .count()
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn count_exterior_surface_area(cubes: &[Point3]) -> usize {
+    let lava_fields = cubes.iter().copied().collect::<HashSet<_>>();
+    let (min, max) = cubes.iter().fold(
+        (
+            Point3::new(isize::MAX, isize::MAX, isize::MAX),
+            Point3::new(isize::MIN, isize::MIN, isize::MIN),
+        ),
+        |(min, max), item| {
+            (
+                Point3::new(min.x.min(item.x), min.y.min(item.y), min.z.min(item.z)),
+                Point3::new(max.x.max(item.x), max.y.max(item.y), max.z.max(item.z)),
+            )
+        },
+    );
+    let (min, max) = (min - Point3::new(1, 1, 1), max + Point3::new(1, 1, 1));
+    let in_bounds = |p: &Point3| {
+        (min.x..=max.x).contains(&p.x)
+            && (min.y..=max.y).contains(&p.y)
+            && (min.z..=max.z).contains(&p.z)
+    };
+
+    let mut exterior = HashSet::new();
+    let mut q = vec![min];
+    while let Some(item) = q.pop() {
+        exterior.insert(item);
+        for next in item.neighbours6() {
+            if in_bounds(&next) && !lava_fields.contains(&next) && !exterior.contains(&next) {
+                q.push(next);
+            }
+        }
+    }
+
+    cubes
+        .iter()
+        .map(|item| {
+            item.neighbours6()
+                .filter(|neighbour| exterior.contains(neighbour))
+                .count()
+        })
+        .sum()
+}
+
+fn group_cubes(cubes: &[Point3]) -> Vec<HashSet<Point3>> {
+    let mut groups: Vec<HashSet<Point3>> = vec![];
+    for cube in cubes {
+        if groups.iter().any(|group| {
+            group
+                .iter()
+                .any(|cube2| cube.manhattan_distance(*cube2) <= 1)
+        }) {
+            groups
+                .iter_mut()
+                .filter(|group| {
+                    group
+                        .iter()
+                        .any(|cube2| cube.manhattan_distance(*cube2) <= 1)
+                })
+                .for_each(|group| {
+                    group.insert(*cube);
+                });
+        } else {
+            groups.push(HashSet::from_iter(vec![*cube]));
+        }
+    }
+    loop {
+        let cartesian_prod_iter = (0..groups.len()).cartesian_product(0..groups.len());
+        if cartesian_prod_iter
+            .map(|(i, j)| {
+                if i != j
+                    && groups[i].iter().any(|item| {
+                        groups[j]
+                            .iter()
+                            .any(|item2| item.manhattan_distance(*item2) <= 1)
+                    })
+                {
+                    let group_to_move = std::mem::take(&mut groups[j]);
+                    groups[i].extend(group_to_move);
+                    1
+                } else {
+                    0
+                }
+            })
+            .sum::<usize>()
+            == 0
+        {
+            break;
+        }
+        groups.retain(|group| !group.is_empty());
+    }
+
+    groups
+}
+
+fn parse(input: &str) -> Result<Vec<Point3>, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .map(|l| {
+            let values = l
+                .split_terminator(',')
+                .map(|s| source.number(s))
+                .collect::<Result<Vec<_>, _>>()?;
+            match values[..] {
+                [x, y, z] => Ok(Point3::new(x, y, z)),
+                _ => Err(source.error(l, "expected `X,Y,Z`")),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_test1() {
+        let cubes = vec![Point3::new(1, 1, 1), Point3::new(2, 1, 1)];
+        assert_eq!(count_surface_area(&cubes), 10);
+    }
+
+    #[test]
+    fn part1_test2() {
+        let cubes = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_surface_area(&cubes), 64);
+    }
+
+    #[test]
+    fn part2_test() {
+        let cubes = parse(fixture!("test-input")).unwrap();
+        assert_eq!(count_exterior_surface_area(&cubes), 58);
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day18>();
+    }
+
+    proptest! {
+        #[test]
+        fn exterior_within_total_test(
+            cubes in prop::collection::hash_set((0..5isize, 0..5isize, 0..5isize), 1..60),
+        ) {
+            let cubes = cubes
+                .into_iter()
+                .map(|(x, y, z)| Point3::new(x, y, z))
+                .collect::<Vec<_>>();
+            prop_assert!(count_exterior_surface_area(&cubes) <= count_surface_area(&cubes));
+        }
+    }
+}
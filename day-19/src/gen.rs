@@ -0,0 +1,35 @@
+//! `size` blueprints with costs in the ranges of the puzzle inputs.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 1;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (1..=size.max(MIN_SIZE))
+        .map(|id| {
+            format!(
+                "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.\n",
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day19;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day19>(generate, 3);
+    }
+}
@@ -0,0 +1,211 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+use std::{collections::BTreeMap, fmt::Display};
+use tracing::{debug, trace};
+
+pub struct Day19;
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("33")
+        .part2("3472")];
+
+    type Input = BTreeMap<usize, Requirements>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(blueprints: &Self::Input) -> impl Display {
+        calculate_outcome(blueprints)
+    }
+
+    fn part2(blueprints: &Self::Input) -> impl Display {
+        multiply_first_n_max_geodes(blueprints, 3)
+    }
+}
+
+pub type Requirements = BTreeMap<String, Vec<(String, usize)>>;
+
+const MATERIALS: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+const GEODE: usize = 3;
+
+fn calculate_outcome(input: &BTreeMap<usize, Requirements>) -> usize {
+    input
+        .iter()
+        .map(|(&blueprint_id, requirements)| {
+            let max_open_geodes = find_max_open_geodes(requirements, 24);
+            debug!(blueprint_id, max_open_geodes, "quality level");
+            blueprint_id * max_open_geodes
+        })
+        .sum()
+}
+
+fn multiply_first_n_max_geodes(input: &BTreeMap<usize, Requirements>, n: usize) -> usize {
+    input
+        .iter()
+        .take(n)
+        .map(|(&blueprint_id, requirements)| {
+            let max_open_geodes = find_max_open_geodes(requirements, 32);
+            debug!(blueprint_id, max_open_geodes, "geodes in 32 minutes");
+            max_open_geodes
+        })
+        .product()
+}
+
+fn find_max_open_geodes(requirements: &Requirements, time: usize) -> usize {
+    let costs = MATERIALS.map(|robot| {
+        let mut cost = [0; 4];
+        for (material, amount) in &requirements[robot] {
+            cost[MATERIALS.iter().position(|m| m == material).unwrap()] = *amount;
+        }
+        cost
+    });
+    // there is no point in producing more of a material per minute than can be spent
+    let mut max_workers = [usize::MAX; 4];
+    for (material, max) in max_workers.iter_mut().enumerate().take(GEODE) {
+        *max = costs
+            .iter()
+            .map(|cost| cost[material])
+            .max()
+            .unwrap_or_default();
+    }
+    let mut max_open_geodes = 0;
+    find_max_open_geodes_rec(
+        &costs,
+        &max_workers,
+        time,
+        [1, 0, 0, 0],
+        [0; 4],
+        &mut max_open_geodes,
+    );
+    max_open_geodes
+}
+
+fn find_max_open_geodes_rec(
+    costs: &[[usize; 4]; 4],
+    max_workers: &[usize; 4],
+    remaining_time: usize,
+    workers: [usize; 4],
+    balance: [usize; 4],
+    max_open_geodes: &mut usize,
+) {
+    trace!(remaining_time, ?workers, ?balance);
+    *max_open_geodes = (*max_open_geodes).max(balance[GEODE] + workers[GEODE] * remaining_time);
+    // even building a geode robot every remaining minute would not beat the best so far
+    let upper_bound = balance[GEODE]
+        + workers[GEODE] * remaining_time
+        + remaining_time * remaining_time.saturating_sub(1) / 2;
+    if upper_bound <= *max_open_geodes {
+        return;
+    }
+    for robot in (0..MATERIALS.len()).rev() {
+        if workers[robot] >= max_workers[robot] {
+            continue;
+        }
+        let wait_time = (0..GEODE).try_fold(0, |wait_time, material| {
+            let missing = costs[robot][material].saturating_sub(balance[material]);
+            match (missing, workers[material]) {
+                (0, _) => Some(wait_time),
+                (_, 0) => None,
+                (missing, rate) => Some(wait_time.max(missing.div_ceil(rate))),
+            }
+        });
+        let Some(wait_time) = wait_time else {
+            continue;
+        };
+        // a robot built in the last minute can't produce anything anymore
+        if wait_time + 1 >= remaining_time {
+            continue;
+        }
+        let mut new_balance = balance;
+        for material in 0..MATERIALS.len() {
+            new_balance[material] += workers[material] * (wait_time + 1);
+            new_balance[material] -= costs[robot][material];
+        }
+        let mut new_workers = workers;
+        new_workers[robot] += 1;
+        find_max_open_geodes_rec(
+            costs,
+            max_workers,
+            remaining_time - wait_time - 1,
+            new_workers,
+            new_balance,
+            max_open_geodes,
+        );
+    }
+}
+
+fn parse(input: &str) -> Result<BTreeMap<usize, Requirements>, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let mut requirements = BTreeMap::new();
+            let content = source.strip_prefix(line, "Blueprint ")?;
+            let (blueprint_id, content) = source.split_once(content, ":")?;
+            let content = source.strip_prefix(content, " Each ore robot costs ")?;
+            let (ore_robot_ore_amount, content) = source.split_once(content, " ")?;
+            requirements.insert(
+                "ore".into(),
+                vec![("ore".into(), source.number(ore_robot_ore_amount)?)],
+            );
+            let content = source.strip_prefix(content, "ore. Each clay robot costs ")?;
+            let (clay_robot_ore_amount, content) = source.split_once(content, " ")?;
+            requirements.insert(
+                "clay".into(),
+                vec![("ore".into(), source.number(clay_robot_ore_amount)?)],
+            );
+            let content = source.strip_prefix(content, "ore. Each obsidian robot costs ")?;
+            let (obsidian_robot_ore_amount, content) = source.split_once(content, " ")?;
+            let content = source.strip_prefix(content, "ore and ")?;
+            let (obsidian_robot_clay_amount, content) = source.split_once(content, " ")?;
+            let content = source.strip_prefix(content, "clay. Each geode robot costs ")?;
+            requirements.insert(
+                "obsidian".into(),
+                vec![
+                    ("ore".into(), source.number(obsidian_robot_ore_amount)?),
+                    ("clay".into(), source.number(obsidian_robot_clay_amount)?),
+                ],
+            );
+            let (geode_robot_ore_amount, content) = source.split_once(content, " ")?;
+            let geode_robot_obsidian_amount =
+                source.strip_suffix(source.strip_prefix(content, "ore and ")?, " obsidian.")?;
+            requirements.insert(
+                "geode".into(),
+                vec![
+                    ("ore".into(), source.number(geode_robot_ore_amount)?),
+                    (
+                        "obsidian".into(),
+                        source.number(geode_robot_obsidian_amount)?,
+                    ),
+                ],
+            );
+            Ok((source.number(blueprint_id)?, requirements))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+
+    #[test]
+    fn part1_test() {
+        let input = parse(fixture!("test-input")).unwrap();
+        assert_eq!(calculate_outcome(&input), 33);
+    }
+
+    #[test]
+    fn part2_test() {
+        let input = parse(fixture!("test-input")).unwrap();
+        assert_eq!(multiply_first_n_max_geodes(&input, 3), 56 * 62);
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day19>();
+    }
+}
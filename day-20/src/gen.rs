@@ -0,0 +1,36 @@
+//! `size` numbers, exactly one of them 0.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 3;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(MIN_SIZE);
+    let zero = rng.gen_range(0..size);
+    (0..size)
+        .map(|idx| {
+            let value = match idx == zero {
+                true => 0,
+                false => loop {
+                    let value = rng.gen_range(-10_000..=10_000);
+                    if value != 0 {
+                        break value;
+                    }
+                },
+            };
+            format!("{value}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day20;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day20>(generate, 500);
+    }
+}
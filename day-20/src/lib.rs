@@ -0,0 +1,232 @@
+pub mod gen;
+
+use aoc_common::{animation::Render, fixture, Example, ParseError, Solution, Source};
+
+use std::fmt::Display;
+pub struct Day20;
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("3")
+        .part2("1623178306")];
+
+    type Input = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(values: &Self::Input) -> impl Display {
+        sum_groove_coords(values)
+    }
+
+    fn part2(values: &Self::Input) -> impl Display {
+        sum_groove_coords2(values)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    pos: usize,
+    next: usize,
+    prev: usize,
+}
+
+fn sum_groove_coords(initial_state: &[isize]) -> isize {
+    let indices = shuffle(initial_state, 1);
+
+    let mut current = initial_state.iter().position(|&v| v == 0).unwrap();
+    let interesting_values = [1_000, 2_000, 3_000];
+    let mut sum = 0;
+    for i in 0..=3_000 {
+        if interesting_values.contains(&i) {
+            sum += initial_state[current];
+        }
+        current = indices[current].next;
+    }
+
+    sum
+}
+
+fn sum_groove_coords2(initial_state: &[isize]) -> isize {
+    let initial_state = initial_state
+        .iter()
+        .map(|v| v * 811589153)
+        .collect::<Vec<_>>();
+    let indices = shuffle(&initial_state, 10);
+
+    let mut current = initial_state.iter().position(|&v| v == 0).unwrap();
+    let interesting_values = [1_000, 2_000, 3_000];
+    let mut sum = 0;
+    for i in 0..=3_000 {
+        if interesting_values.contains(&i) {
+            sum += initial_state[current];
+        }
+        current = indices[current].next;
+    }
+
+    sum
+}
+
+fn shuffle(initial_state: &[isize], n: usize) -> Vec<Item> {
+    let values = initial_state.to_vec();
+    let mut indices = values
+        .iter()
+        .enumerate()
+        .map(|(idx, _)| Item {
+            pos: idx,
+            prev: if idx == 0 { values.len() - 1 } else { idx - 1 },
+            next: (idx + 1) % values.len(),
+        })
+        .collect::<Vec<_>>();
+    for _ in 0..n {
+        for (curr_pos, &value) in initial_state.iter().enumerate() {
+            move_number(&mut indices, curr_pos, value);
+        }
+    }
+    indices
+}
+
+/// Moves the number at `curr_pos` in the original list `value` places forward or backward.
+fn move_number(indices: &mut [Item], curr_pos: usize, value: isize) {
+    let mut i = value % (indices.len() as isize - 1);
+    while i != 0 {
+        let current = indices[curr_pos];
+        let (next, next2, prev) = if i < 0 {
+            (current.prev, indices[current.prev].prev, current.next)
+        } else {
+            (current.next, indices[current.next].next, current.prev)
+        };
+        // swap positions
+        (indices[curr_pos].pos, indices[next].pos) = (indices[next].pos, indices[curr_pos].pos);
+        if i < 0 {
+            (
+                indices[curr_pos].prev,
+                indices[curr_pos].next,
+                indices[next].prev,
+                indices[next].next,
+            ) = (indices[next].prev, next, curr_pos, indices[curr_pos].next);
+            indices[prev].prev = next;
+            indices[next2].next = curr_pos;
+        } else {
+            (
+                indices[curr_pos].prev,
+                indices[curr_pos].next,
+                indices[next].prev,
+                indices[next].next,
+            ) = (next, indices[next].next, indices[curr_pos].prev, curr_pos);
+            indices[prev].next = next;
+            indices[next2].prev = curr_pos;
+        }
+        i -= i.signum();
+    }
+}
+
+/// One round of mixing the numbers of part 1, moving one number per step.
+struct Mixing {
+    values: Vec<isize>,
+    indices: Vec<Item>,
+    moved: usize,
+}
+impl Render for Mixing {
+    fn render(&self) -> String {
+        let mut current = self.values.iter().position(|&v| v == 0).unwrap();
+        let mut numbers = Vec::with_capacity(self.values.len());
+        for _ in 0..self.values.len() {
+            numbers.push(self.values[current].to_string());
+            current = self.indices[current].next;
+        }
+        format!(
+            "moved {} of {}\n{}",
+            self.moved,
+            self.values.len(),
+            numbers.join(", ")
+        )
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&value) = self.values.get(self.moved) else {
+            return false;
+        };
+        move_number(&mut self.indices, self.moved, value);
+        self.moved += 1;
+        true
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Render>, ParseError> {
+    let values = parse(input)?;
+    Ok(Box::new(Mixing {
+        indices: shuffle(&values, 0),
+        values,
+        moved: 0,
+    }))
+}
+
+fn parse(input: &str) -> Result<Vec<isize>, ParseError> {
+    let source = Source::new(input);
+    let numbers = input
+        .lines()
+        .map(|line| source.number::<isize>(line))
+        .collect::<Result<Vec<_>, _>>()?;
+    if !numbers.contains(&0) {
+        return Err(source.eof("the file contains no 0"));
+    }
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_test() {
+        let values = parse(fixture!("test-input")).unwrap();
+        assert_eq!(sum_groove_coords(&values), 3);
+    }
+
+    #[test]
+    fn part2_test() {
+        let values = parse(fixture!("test-input")).unwrap();
+        assert_eq!(sum_groove_coords2(&values), 1623178306);
+    }
+
+    #[test]
+    fn render_test() {
+        let mut mixing = animation(fixture!("test-input")).unwrap();
+        assert_eq!(mixing.render(), "moved 0 of 7\n0, 4, 1, 2, -3, 3, -2");
+        assert!(mixing.step());
+        assert_eq!(mixing.render(), "moved 1 of 7\n0, 4, 2, 1, -3, 3, -2");
+        while mixing.step() {}
+        assert_eq!(mixing.render(), "moved 7 of 7\n0, 3, -2, 1, 2, -3, 4");
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day20>();
+    }
+
+    proptest! {
+        #[test]
+        fn shuffle_preserves_values_test(
+            values in prop::collection::vec(-20isize..20, 2..12),
+            rounds in 1..3usize,
+        ) {
+            let indices = shuffle(&values, rounds);
+            let mut current = 0;
+            let mut mixed = Vec::new();
+            for _ in 0..values.len() {
+                prop_assert_eq!(indices[indices[current].next].prev, current);
+                mixed.push(values[current]);
+                current = indices[current].next;
+            }
+            prop_assert_eq!(current, 0, "the list is not a single ring");
+            let mut expected = values.clone();
+            expected.sort();
+            mixed.sort();
+            prop_assert_eq!(mixed, expected);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! `size` operations between `humn` and `root`, each taking its other operand from a small tree
+//! of monkeys.
+//!
+//! The chain is built up from `humn` for both the number it yells and the number it should yell,
+//! so that divisions are exact and part 2 has an integer answer.
+
+use aoc_common::gen::{Rng, SliceRandom, StdRng};
+use std::collections::HashSet;
+
+pub const MIN_SIZE: usize = 1;
+
+const MAX_VALUE: isize = 1_000_000_000_000;
+
+struct Monkeys<'a> {
+    rng: &'a mut StdRng,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+impl Monkeys<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name = (0..4)
+                .map(|_| self.rng.gen_range(b'a'..=b'z') as char)
+                .collect::<String>();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn add(&mut self, name: &str, job: String) {
+        self.jobs.push(format!("{name}: {job}\n"));
+    }
+
+    /// A new monkey yelling `value` after waiting on up to `depth` levels of other monkeys.
+    fn yelling(&mut self, value: isize, depth: usize) -> String {
+        let name = self.name();
+        let divisor = (2..=9).find(|d| value % d == 0 && value > *d);
+        let job = match (depth, self.rng.gen_range(0..4), divisor) {
+            (0, _, _) => value.to_string(),
+            (_, 0, _) if value > 1 => {
+                let left = self.rng.gen_range(1..value);
+                self.operation(left, '+', value - left, depth)
+            }
+            (_, 1, _) => {
+                let right = self.rng.gen_range(1..=100);
+                self.operation(value + right, '-', right, depth)
+            }
+            (_, 2, Some(divisor)) => self.operation(divisor, '*', value / divisor, depth),
+            _ if value < MAX_VALUE => {
+                let divisor = self.rng.gen_range(2..=5);
+                self.operation(value * divisor, '/', divisor, depth)
+            }
+            _ => value.to_string(),
+        };
+        self.add(&name, job);
+        name
+    }
+
+    fn operation(&mut self, left: isize, op: char, right: isize, depth: usize) -> String {
+        let left = self.yelling(left, depth - 1);
+        let right = self.yelling(right, depth - 1);
+        format!("{left} {op} {right}")
+    }
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut monkeys = Monkeys {
+        rng,
+        names: HashSet::new(),
+        jobs: Vec::new(),
+    };
+    let yelled = monkeys.rng.gen_range(1..=1000);
+    let mut values = [yelled, monkeys.rng.gen_range(1..=1000)];
+    monkeys.add("humn", yelled.to_string());
+    let mut below = "humn".to_string();
+    for _ in 0..size.max(MIN_SIZE) {
+        let largest = values[0].max(values[1]);
+        let depth = monkeys.rng.gen_range(0..=3);
+        let divisor = (2..=5).find(|d| values.iter().all(|value| value % d == 0));
+        let (job, next) = match monkeys.rng.gen_range(0..4) {
+            0 if largest < MAX_VALUE => {
+                let operand = monkeys.rng.gen_range(1..=100);
+                let other = monkeys.yelling(operand, depth);
+                (format!("{below} + {other}"), values.map(|v| v + operand))
+            }
+            1 => {
+                let operand = largest + monkeys.rng.gen_range(1..=100);
+                let other = monkeys.yelling(operand, depth);
+                (format!("{other} - {below}"), values.map(|v| operand - v))
+            }
+            2 if largest < MAX_VALUE => {
+                let operand = monkeys.rng.gen_range(2..=5);
+                let other = monkeys.yelling(operand, depth);
+                (format!("{other} * {below}"), values.map(|v| operand * v))
+            }
+            _ => match divisor {
+                Some(divisor) => {
+                    let other = monkeys.yelling(divisor, depth);
+                    (format!("{below} / {other}"), values.map(|v| v / divisor))
+                }
+                None => {
+                    let operand = monkeys.rng.gen_range(1..=100);
+                    let other = monkeys.yelling(operand, depth);
+                    (format!("{below} - {other}"), values.map(|v| v - operand))
+                }
+            },
+        };
+        let name = monkeys.name();
+        monkeys.add(&name, job);
+        below = name;
+        values = next;
+    }
+    let other = monkeys.yelling(values[1], 3);
+    monkeys.add("root", format!("{below} + {other}"));
+
+    let mut jobs = monkeys.jobs;
+    jobs.shuffle(rng);
+    jobs.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eval_monkey_exprs, find_number_to_yell, parse, Day21, Value};
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day21>(generate, 50);
+    }
+
+    #[test]
+    fn part2_test() {
+        let input = generate(&mut aoc_common::gen::rng(1), 50);
+        let mut monkeys = parse(&input).unwrap();
+        let answer = find_number_to_yell(&monkeys, "root", None);
+        monkeys.insert("humn".into(), Value::Number(answer));
+        let Value::Expr { v1, v2, .. } = &monkeys["root"] else {
+            unreachable!()
+        };
+        let eval = |name: &str| eval_monkey_exprs(&monkeys, &monkeys[name], false);
+        assert_eq!(eval(v1), eval(v2));
+    }
+}
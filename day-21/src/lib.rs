@@ -0,0 +1,194 @@
+pub mod gen;
+
+use aoc_common::{fixture, Example, ParseError, Solution, Source};
+use std::{collections::HashMap, fmt::Display};
+
+pub struct Day21;
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("152")
+        .part2("301")];
+
+    type Input = HashMap<String, Value>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> impl Display {
+        eval_monkey_exprs(monkeys, &monkeys["root"], false).expect("root depends on humn")
+    }
+
+    fn part2(monkeys: &Self::Input) -> impl Display {
+        find_number_to_yell(monkeys, "root", None)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Number(isize),
+    Expr { op: Op, v1: String, v2: String },
+}
+
+#[derive(Debug, Clone)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+fn find_number_to_yell(
+    monkey_exprs: &HashMap<String, Value>,
+    item: &str,
+    target: Option<isize>,
+) -> isize {
+    let root = monkey_exprs.get(item).unwrap();
+    let (left, right, op) = match root {
+        Value::Expr { v1, v2, op } => (v1, v2, op),
+        _ => unreachable!(),
+    };
+    let left_val;
+    let right_val;
+    if left == "humn" {
+        right_val = eval_monkey_exprs(monkey_exprs, monkey_exprs.get(right).unwrap(), true);
+        left_val = None;
+    } else if right == "humn" {
+        left_val = eval_monkey_exprs(monkey_exprs, monkey_exprs.get(left).unwrap(), true);
+        right_val = None;
+    } else {
+        left_val = eval_monkey_exprs(monkey_exprs, monkey_exprs.get(left).unwrap(), true);
+        right_val = eval_monkey_exprs(monkey_exprs, monkey_exprs.get(right).unwrap(), true);
+    }
+    let calculate_remaining: Box<dyn Fn(isize) -> isize> = match (item, op) {
+        ("root", _) => Box::new(|value| value),
+        (_, Op::Add) => Box::new(|value| target.unwrap_or_default() - value),
+        (_, Op::Sub) => Box::new(|value| {
+            if left_val.is_some() {
+                value - target.unwrap_or_default()
+            } else {
+                target.unwrap_or_default() + value
+            }
+        }),
+        (_, Op::Mul) => Box::new(|value| target.unwrap_or_default() / value),
+        (_, Op::Div) => Box::new(|value| target.unwrap_or_default() * value),
+    };
+    match (left_val, right_val) {
+        (Some(left_res), None) if right != "humn" => {
+            find_number_to_yell(monkey_exprs, right, Some(calculate_remaining(left_res)))
+        }
+        (None, Some(right_res)) if left != "humn" => {
+            find_number_to_yell(monkey_exprs, left, Some(calculate_remaining(right_res)))
+        }
+        (Some(left_res), None) if right == "humn" => calculate_remaining(left_res),
+        (None, Some(right_res)) if left == "humn" => calculate_remaining(right_res),
+        invalid => unreachable!("{invalid:?}"),
+    }
+}
+
+fn eval_monkey_exprs(
+    monkey_exprs: &HashMap<String, Value>,
+    item: &Value,
+    find_human_value: bool,
+) -> Option<isize> {
+    match item {
+        Value::Number(n) => Some(*n),
+        Value::Expr { op, v1, v2 } => {
+            let (val1, val2) = (
+                if find_human_value && v1 == "humn" {
+                    None
+                } else {
+                    Some(eval_monkey_exprs(
+                        monkey_exprs,
+                        monkey_exprs.get(v1).unwrap(),
+                        find_human_value,
+                    )?)
+                },
+                if find_human_value && v2 == "humn" {
+                    None
+                } else {
+                    Some(eval_monkey_exprs(
+                        monkey_exprs,
+                        monkey_exprs.get(v2).unwrap(),
+                        find_human_value,
+                    )?)
+                },
+            );
+            Some(match op {
+                Op::Add => val1? + val2?,
+                Op::Sub => val1? - val2?,
+                Op::Mul => val1? * val2?,
+                Op::Div => val1? / val2?,
+            })
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<HashMap<String, Value>, ParseError> {
+    let source = Source::new(input);
+    let mut references = Vec::new();
+    let monkeys = input
+        .lines()
+        .map(|l| {
+            let (monkey, value_str) = source.split_once(l, ": ")?;
+            let value = match value_str.chars().next() {
+                Some(x) if x.is_alphabetic() => {
+                    let parts = value_str.splitn(3, ' ').collect::<Vec<_>>();
+                    let [v1, op, v2] = parts[..] else {
+                        return Err(source.error(value_str, "expected `aaaa + bbbb`"));
+                    };
+                    references.extend([v1, v2]);
+                    Value::Expr {
+                        op: match op {
+                            "+" => Op::Add,
+                            "-" => Op::Sub,
+                            "*" => Op::Mul,
+                            "/" => Op::Div,
+                            _ => return Err(source.error(op, "expected one of +, -, *, /")),
+                        },
+                        v1: v1.into(),
+                        v2: v2.into(),
+                    }
+                }
+                _ => Value::Number(source.number(value_str)?),
+            };
+
+            Ok((monkey.into(), value))
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    if let Some(name) = references.iter().find(|&&name| !monkeys.contains_key(name)) {
+        return Err(source.error(name, "no such monkey"));
+    }
+    match monkeys.get("root") {
+        Some(Value::Expr { .. }) => Ok(monkeys),
+        Some(Value::Number(_)) => Err(source.eof("`root` must compare two monkeys")),
+        None => Err(source.eof("missing the `root` monkey")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+
+    #[test]
+    fn part1_test() {
+        let monkeys = parse(fixture!("test-input")).unwrap();
+        assert_eq!(
+            eval_monkey_exprs(&monkeys, monkeys.get("root").unwrap(), false),
+            Some(152)
+        );
+    }
+
+    #[test]
+    fn part2_test() {
+        let monkeys = parse(fixture!("test-input")).unwrap();
+        assert_eq!(find_number_to_yell(&monkeys, "root", None), 301);
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day21>();
+    }
+}
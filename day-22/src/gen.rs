@@ -0,0 +1,43 @@
+//! A path of `size` moves across a map folded like the puzzle inputs, with faces 50 tiles wide.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 1;
+
+const SIDE: usize = 50;
+/// First and last face, in units of `SIDE`, of every band of rows.
+const LAYOUT: [(usize, usize); 4] = [(1, 3), (1, 2), (0, 2), (0, 1)];
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for (band, &(first, last)) in LAYOUT.iter().enumerate() {
+        for y in band * SIDE..(band + 1) * SIDE {
+            input += &" ".repeat(first * SIDE);
+            for x in first * SIDE..last * SIDE {
+                let open = (y, x) == (0, SIDE) || rng.gen_bool(0.9);
+                input.push(if open { '.' } else { '#' });
+            }
+            input.push('\n');
+        }
+    }
+    input.push('\n');
+    for idx in 0..size.max(MIN_SIZE) {
+        if idx > 0 {
+            input.push(if rng.gen_bool(0.5) { 'R' } else { 'L' });
+        }
+        input += &rng.gen_range(1..=SIDE).to_string();
+    }
+    input + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day22;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day22>(generate, 100);
+    }
+}
@@ -0,0 +1,438 @@
+pub mod gen;
+
+use aoc_common::{fixture, Direction4, Example, Grid, ParseError, Point2, Solution, Source};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+};
+
+pub struct Day22;
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("6032")
+        .part2("5031")];
+
+    type Input = MonkeyMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(monkey_map: &Self::Input) -> impl Display {
+        let mut monkey_map = monkey_map.clone();
+        monkey_map.apply_actions();
+        monkey_map.get_score()
+    }
+
+    fn part2(monkey_map: &Self::Input) -> impl Display {
+        let mut monkey_map = monkey_map.clone();
+        monkey_map.apply_actions2();
+        monkey_map.get_score()
+    }
+}
+
+#[derive(Clone)]
+pub struct MonkeyMap {
+    map: Grid<char>,
+    sides_map: Grid<char>,
+    actions: Vec<Action>,
+    pos: Point2,
+    direction: Direction4,
+    transformations: BTreeMap<char, BTreeMap<char, Direction4>>,
+}
+impl MonkeyMap {
+    fn apply_actions(&mut self) {
+        for &action in &self.actions {
+            match action {
+                Action::Move(steps) => {
+                    for _ in 0..steps {
+                        let mut next_pos = self.pos + self.direction.offset();
+                        if self
+                            .map
+                            .get_signed(next_pos.yx())
+                            .is_none_or(|&ch| ch == ' ')
+                        {
+                            match self.direction {
+                                Direction4::Down => {
+                                    next_pos.y = self
+                                        .map
+                                        .column(next_pos.x as usize)
+                                        .position(|&ch| ch != ' ')
+                                        .unwrap()
+                                        as isize;
+                                }
+                                Direction4::Up => {
+                                    next_pos.y = self
+                                        .map
+                                        .column(next_pos.x as usize)
+                                        .rposition(|&ch| ch != ' ')
+                                        .unwrap()
+                                        as isize;
+                                }
+                                Direction4::Right => {
+                                    next_pos.x = self
+                                        .map
+                                        .row(next_pos.y as usize)
+                                        .iter()
+                                        .position(|&ch| ch != ' ')
+                                        .unwrap()
+                                        as isize;
+                                }
+                                Direction4::Left => {
+                                    next_pos.x = self
+                                        .map
+                                        .row(next_pos.y as usize)
+                                        .iter()
+                                        .rposition(|&ch| ch != ' ')
+                                        .unwrap()
+                                        as isize;
+                                }
+                            }
+                        }
+                        if self.map[(next_pos.y as usize, next_pos.x as usize)] == '#' {
+                            break;
+                        }
+                        self.pos = next_pos;
+                    }
+                }
+                Action::Right => self.direction = self.direction.turn_right(),
+                Action::Left => self.direction = self.direction.turn_left(),
+            }
+        }
+    }
+
+    fn apply_actions2(&mut self) {
+        let actions = self.actions.clone();
+        for action in actions.into_iter() {
+            match action {
+                Action::Move(steps) => {
+                    for _ in 0..steps {
+                        let mut reassigned_move = None;
+                        let mut next_pos = self.pos + self.direction.offset();
+                        if self
+                            .map
+                            .get_signed(next_pos.yx())
+                            .is_none_or(|&ch| ch == ' ')
+                        {
+                            let (new_pos, next_dir) = self.get_next_transf_pos();
+                            next_pos = new_pos;
+                            reassigned_move = Some(next_dir);
+                        }
+                        if self.map[(next_pos.y as usize, next_pos.x as usize)] == '#' {
+                            break;
+                        }
+                        self.pos = next_pos;
+                        if let Some(dir) = reassigned_move {
+                            self.direction = dir;
+                        }
+                    }
+                }
+                Action::Right => self.direction = self.direction.turn_right(),
+                Action::Left => self.direction = self.direction.turn_left(),
+            }
+        }
+    }
+
+    fn get_score(&self) -> usize {
+        1000 * (self.pos.y + 1) as usize
+            + 4 * (self.pos.x + 1) as usize
+            + facing_score(self.direction)
+    }
+
+    fn get_side_size(&self) -> isize {
+        let side_width = self
+            .map
+            .rows()
+            .map(|row| row.iter().filter(|&&ch| ch != ' ').count())
+            .min()
+            .unwrap() as isize;
+        side_width
+    }
+
+    fn get_next_transf_pos(&mut self) -> (Point2, Direction4) {
+        let curr_side = self.sides_map[(self.pos.y as usize, self.pos.x as usize)];
+        let tranf_dir = self
+            .transformations
+            .get(&curr_side)
+            .unwrap()
+            .iter()
+            .find(|&(_k, &v)| v == self.direction)
+            .unwrap();
+        let reverse_tranf_dir = self
+            .transformations
+            .get(tranf_dir.0)
+            .unwrap()
+            .get(&curr_side)
+            .unwrap();
+
+        let from_side_start = self.find_side_start_pos(curr_side);
+        let to_side_start = self.find_side_start_pos(*tranf_dir.0);
+        let last = self.get_side_size() - 1;
+        // offset along the edge being left, going clockwise around the current side
+        let offset = match tranf_dir.1 {
+            Direction4::Up => self.pos.x - from_side_start.x,
+            Direction4::Right => self.pos.y - from_side_start.y,
+            Direction4::Down => last - (self.pos.x - from_side_start.x),
+            Direction4::Left => last - (self.pos.y - from_side_start.y),
+        };
+        // glued edges run the other way around the next side
+        let offset = last - offset;
+        let (y, x) = match reverse_tranf_dir {
+            Direction4::Up => (to_side_start.y, to_side_start.x + offset),
+            Direction4::Right => (to_side_start.y + offset, to_side_start.x + last),
+            Direction4::Down => (to_side_start.y + last, to_side_start.x + last - offset),
+            Direction4::Left => (to_side_start.y + last - offset, to_side_start.x),
+        };
+        (Point2::new(x, y), reverse_tranf_dir.opposite())
+    }
+
+    fn mark_sides(&mut self) {
+        let side_size = self.get_side_size();
+        let mut y = 0;
+        let mut current_side = b'1';
+        while y < self.map.height() {
+            let mut x = 0;
+            while x < self.map.width() {
+                if self.map[(y, x)] != ' ' {
+                    for curr_y in y..y + side_size as usize {
+                        for curr_x in x..x + side_size as usize {
+                            self.sides_map[(curr_y, curr_x)] = current_side as char;
+                        }
+                    }
+                    current_side += 1;
+                }
+                x += side_size as usize;
+            }
+            y += side_size as usize;
+        }
+    }
+
+    fn find_side_start_pos(&self, side: char) -> Point2 {
+        let side_size = self.get_side_size();
+        let mut y = 0;
+        while y < self.sides_map.height() {
+            let mut x = 0;
+            while x < self.sides_map.width() {
+                if self.sides_map[(y, x)] == side {
+                    return Point2::new(x as isize, y as isize);
+                }
+                x += side_size as usize;
+            }
+            y += side_size as usize;
+        }
+        unreachable!()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    Right,
+    Left,
+    Move(usize),
+}
+
+fn facing_score(direction: Direction4) -> usize {
+    match direction {
+        Direction4::Right => 0,
+        Direction4::Down => 1,
+        Direction4::Left => 2,
+        Direction4::Up => 3,
+    }
+}
+
+/// Folds the map into a cube, checking that it is six square faces, and returns for every side
+/// the direction to leave it by to reach each of its neighbours.
+///
+/// Sides are named `'1'` onwards in reading order, as in [`MonkeyMap::mark_sides`].
+fn fold_cube(
+    source: &Source,
+    map_str: &str,
+    map: &Grid<char>,
+) -> Result<BTreeMap<char, BTreeMap<char, Direction4>>, ParseError> {
+    let lines = map_str.lines().collect::<Vec<_>>();
+    let tiles = |row: &[char]| row.iter().filter(|&&ch| ch != ' ').count();
+    if let Some(y) = map.rows().position(|row| tiles(row) == 0) {
+        return Err(source.error(lines[y], "expected tiles on every row"));
+    }
+    let side = map.rows().map(tiles).min().unwrap_or(0);
+    let mut faces = Vec::new();
+    for y in (0..map.height()).step_by(side) {
+        for x in (0..map.width()).step_by(side) {
+            let is_face = map[(y, x)] != ' ';
+            for dy in 0..side {
+                let row = (0..side).map(|dx| map.get((y + dy, x + dx)).copied().unwrap_or(' '));
+                if row.into_iter().any(|ch| (ch != ' ') != is_face) {
+                    let line = lines.get(y + dy).unwrap_or(lines.last().unwrap());
+                    return Err(
+                        source.error(line, format!("expected faces of {side} by {side} tiles"))
+                    );
+                }
+            }
+            if is_face {
+                faces.push((y / side, x / side));
+            }
+        }
+    }
+    if faces.len() != 6 {
+        return Err(source.error(map_str, format!("expected 6 faces, found {}", faces.len())));
+    }
+
+    // rolls a cube over the net, the faces fold into it if they all end up facing another way
+    let neg = |[x, y, z]: [i32; 3]| [-x, -y, -z];
+    let mut frames = HashMap::from([(faces[0], ([1, 0, 0], [0, 1, 0], [0, 0, 1]))]);
+    let mut queue = vec![faces[0]];
+    while let Some((y, x)) = queue.pop() {
+        let (right, down, normal) = frames[&(y, x)];
+        let neighbours = [
+            ((y, x + 1), (neg(normal), down, right)),
+            ((y, x.wrapping_sub(1)), (normal, down, neg(right))),
+            ((y + 1, x), (right, neg(normal), down)),
+            ((y.wrapping_sub(1), x), (right, normal, neg(down))),
+        ];
+        for (face, frame) in neighbours {
+            if faces.contains(&face) && !frames.contains_key(&face) {
+                frames.insert(face, frame);
+                queue.push(face);
+            }
+        }
+    }
+    let normals = faces.iter().map(|face| frames[face].2).collect::<Vec<_>>();
+    if normals.iter().collect::<HashSet<_>>().len() != 6 {
+        return Err(source.error(map_str, "the faces do not fold into a cube"));
+    }
+
+    // leaving a face towards one of its edges lands on the face turned that way
+    let name = |i: usize| (b'1' + i as u8) as char;
+    let mut transformations = BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        let (right, down, _) = frames[face];
+        let edges = [
+            (Direction4::Right, right),
+            (Direction4::Left, neg(right)),
+            (Direction4::Down, down),
+            (Direction4::Up, neg(down)),
+        ];
+        let neighbours = edges
+            .into_iter()
+            .map(|(direction, towards)| {
+                let j = normals.iter().position(|&n| n == towards).unwrap();
+                (name(j), direction)
+            })
+            .collect();
+        transformations.insert(name(i), neighbours);
+    }
+    Ok(transformations)
+}
+
+fn parse(content: &str) -> Result<MonkeyMap, ParseError> {
+    let source = Source::new(content);
+    let (map_str, actions_str) = source.split_once(content, "\n\n")?;
+    let map = Grid::parse_padded(map_str, ' ', |c| matches!(c, ' ' | '.' | '#').then_some(c))?;
+    let actions_str = actions_str.trim();
+    if actions_str.is_empty() {
+        return Err(source.eof("expected the path after the map"));
+    }
+    let mut actions = Vec::new();
+    let mut rest = actions_str;
+    while let Some(first) = rest.chars().next() {
+        let len = match rest.find(|ch: char| !ch.is_ascii_digit()) {
+            Some(0) => first.len_utf8(),
+            Some(len) => len,
+            None => rest.len(),
+        };
+        let (token, tail) = rest.split_at(len);
+        actions.push(match token {
+            "R" => Action::Right,
+            "L" => Action::Left,
+            steps => Action::Move(source.number(steps)?),
+        });
+        rest = tail;
+    }
+    let transformations = fold_cube(&source, map_str, &map)?;
+    let pos = Point2::new(
+        map.rows()
+            .next()
+            .and_then(|row| row.iter().position(|&c| c == '.'))
+            .ok_or_else(|| source.error(map_str, "no open tile on the top row"))? as isize,
+        0,
+    );
+
+    let mut monkey_map = MonkeyMap {
+        sides_map: map.clone(),
+        map,
+        actions,
+        pos,
+        direction: Direction4::Right,
+        transformations,
+    };
+    monkey_map.mark_sides();
+
+    Ok(monkey_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+
+    #[test]
+    fn part1_test() {
+        let mut monkey_map = parse(fixture!("test-input")).unwrap();
+        monkey_map.apply_actions();
+        assert_eq!(monkey_map.get_score(), 6032);
+    }
+
+    #[test]
+    fn part2_test() {
+        let mut monkey_map = parse(fixture!("test-input")).unwrap();
+        monkey_map.apply_actions2();
+        assert_eq!(monkey_map.get_score(), 5031);
+    }
+
+    #[test]
+    fn parse_path_test() {
+        let map = fixture!("test-input").split("\n\n").next().unwrap();
+        let monkey_map = parse(&format!("{map}\n\nR5L\n")).unwrap();
+        assert!(matches!(
+            monkey_map.actions[..],
+            [Action::Right, Action::Move(5), Action::Left]
+        ));
+        let err = parse(&format!("{map}\n\n10X5\n")).err().unwrap();
+        assert_eq!((err.column, err.text.as_str()), (3, "X"));
+    }
+
+    #[test]
+    fn parse_net_test() {
+        let err = parse("..\n..\n\n5\n").err().unwrap();
+        assert_eq!(err.reason, "expected 6 faces, found 1");
+
+        let err = parse("...\n..\n\n5\n").err().unwrap();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (1, "expected faces of 2 by 2 tiles")
+        );
+
+        assert!(parse(" .\n...\n .\n .\n\n5\n").is_ok());
+        let err = parse(".\n.\n.\n.\n.\n.\n\n5\n").err().unwrap();
+        assert_eq!(err.reason, "the faces do not fold into a cube");
+    }
+
+    #[test]
+    fn wrap_around_cube_test() {
+        // walking four tiles straight on a cube of side 1 comes back to the start
+        for (path, score) in [("4", 1008), ("R4", 1009), ("L4", 1011), ("RR4", 1010)] {
+            let monkey_map = parse(&format!(" .\n...\n .\n .\n\n{path}\n")).unwrap();
+            assert_eq!(
+                Day22::part2(&monkey_map).to_string(),
+                score.to_string(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day22>();
+    }
+}
@@ -0,0 +1,28 @@
+//! A square grove `size` tiles wide, about half of them taken by elves.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 1;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(MIN_SIZE))
+        .map(|_| {
+            let row = (0..size.max(MIN_SIZE))
+                .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                .collect::<String>();
+            row + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day23;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        assert_generates::<Day23>(generate, 16);
+    }
+}
@@ -0,0 +1,228 @@
+pub mod gen;
+
+use aoc_common::{
+    animation::Render, fixture, Direction4, Direction8, Example, ParseError, Point2, Solution,
+    Source,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+pub struct Day23;
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input2", fixture!("test-input2"))
+        .part1("110")
+        .part2("20")];
+
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(world: &Self::Input) -> impl Display {
+        let mut world = world.clone();
+        world.apply_n_rounds(10);
+        world.count_empty_spaces()
+    }
+
+    fn part2(world: &Self::Input) -> impl Display {
+        let mut world = world.clone();
+        world.apply_until_done()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct World {
+    map: HashSet<Point2>,
+    dir_priority: VecDeque<Direction4>,
+}
+impl World {
+    fn apply_n_rounds(&mut self, n: usize) {
+        for _ in 0..n {
+            self.apply_round();
+        }
+    }
+
+    fn apply_until_done(&mut self) -> usize {
+        (1..).find(|_| !self.apply_round()).unwrap()
+    }
+
+    /// Returns whether any elf moved.
+    fn apply_round(&mut self) -> bool {
+        let potential_moves = self
+            .map
+            .iter()
+            .filter_map(|&pos| {
+                if self.is_surrounded(pos) {
+                    for &current_dir in self.dir_priority.iter() {
+                        if get_next_moves(current_dir)
+                            .iter()
+                            .all(|&diff| !self.map.contains(&(pos + diff)))
+                        {
+                            return Some(vec![(pos, pos + current_dir.offset())]);
+                        }
+                    }
+                }
+                None
+            })
+            .flatten()
+            .fold(HashMap::new(), |mut acc, (from, to)| {
+                let item = acc.entry(to).or_insert((vec![], 0));
+                item.0.push(from);
+                item.1 += 1;
+                acc
+            });
+        let mut moved = false;
+        potential_moves
+            .into_iter()
+            .filter_map(|(to, (from, count))| {
+                if count == 1 {
+                    return Some((from[0], to));
+                }
+                None
+            })
+            .for_each(|(from, to)| {
+                self.map.remove(&from);
+                self.map.insert(to);
+                moved = true;
+            });
+        self.dir_priority.rotate_left(1);
+        moved
+    }
+
+    /// Smallest rectangle containing every elf, as `(x_min, x_max, y_min, y_max)`.
+    fn bounds(&self) -> (isize, isize, isize, isize) {
+        self.map.iter().fold(
+            (isize::MAX, isize::MIN, isize::MAX, isize::MIN),
+            |acc, pos| {
+                (
+                    acc.0.min(pos.x),
+                    acc.1.max(pos.x),
+                    acc.2.min(pos.y),
+                    acc.3.max(pos.y),
+                )
+            },
+        )
+    }
+
+    fn count_empty_spaces(&self) -> usize {
+        let (x_min, x_max, y_min, y_max) = self.bounds();
+        (x_min..=x_max)
+            .map(|x| {
+                (y_min..=y_max)
+                    .filter(|&y| !self.map.contains(&Point2::new(x, y)))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn is_surrounded(&self, pos: Point2) -> bool {
+        pos.neighbours8()
+            .any(|neighbour| self.map.contains(&neighbour))
+    }
+}
+
+impl Render for World {
+    fn render(&self) -> String {
+        let (x_min, x_max, y_min, y_max) = self.bounds();
+        (y_min..=y_max)
+            .map(|y| {
+                (x_min..=x_max)
+                    .map(|x| match self.map.contains(&Point2::new(x, y)) {
+                        true => '#',
+                        false => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Plays one round, until no elf moves anymore.
+    fn step(&mut self) -> bool {
+        self.apply_round()
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Render>, ParseError> {
+    Ok(Box::new(parse(input)?))
+}
+
+/// Offsets that must be free for an elf to move towards `dir`.
+fn get_next_moves(dir: Direction4) -> [Point2; 3] {
+    let dir = Direction8::from(dir);
+    [dir.turn_left(), dir, dir.turn_right()].map(Direction8::offset)
+}
+
+fn parse(input: &str) -> Result<World, ParseError> {
+    let source = Source::new(input);
+    let mut map = HashSet::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, (idx, ch)) in line.char_indices().enumerate() {
+            match ch {
+                '#' => {
+                    map.insert(Point2::new(x as isize, y as isize));
+                }
+                '.' => {}
+                _ => {
+                    let text = &line[idx..idx + ch.len_utf8()];
+                    return Err(source.error(text, "expected `#` or `.`"));
+                }
+            }
+        }
+    }
+    Ok(World {
+        map,
+        dir_priority: [
+            Direction4::Up,
+            Direction4::Down,
+            Direction4::Left,
+            Direction4::Right,
+        ]
+        .into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+
+    #[test]
+    fn part1_test1() {
+        let mut world = parse(fixture!("test-input")).unwrap();
+        world.apply_n_rounds(3);
+        assert_eq!(world.count_empty_spaces(), 25);
+    }
+
+    #[test]
+    fn part1_test2() {
+        let mut world = parse(fixture!("test-input2")).unwrap();
+        world.apply_n_rounds(10);
+        assert_eq!(world.count_empty_spaces(), 110);
+    }
+
+    #[test]
+    fn part2_test() {
+        let mut world = parse(fixture!("test-input2")).unwrap();
+        assert_eq!(world.apply_until_done(), 20);
+    }
+
+    #[test]
+    fn render_test() {
+        let mut world = parse(fixture!("test-input")).unwrap();
+        assert!(world.step());
+        assert_eq!(world.render(), "##\n..\n#.\n.#\n#.");
+        assert!(world.step());
+        assert!(world.step());
+        assert!(!world.step());
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day23>();
+    }
+}
@@ -0,0 +1,61 @@
+//! A valley `size` tiles wide and half as high, a quarter of it taken by blizzards.
+//!
+//! Like in the puzzle inputs, no blizzard ever blows up or down the entrance or exit columns,
+//! which would leave the valley through them. Small valleys are easily blocked for good, e.g. by
+//! a `<` and a `>` taking turns in a column, so valleys are drawn again until the expedition
+//! can go back and forth.
+
+use aoc_common::gen::{Rng, StdRng};
+
+pub const MIN_SIZE: usize = 2;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    loop {
+        let input = valley(rng, size);
+        let mut maze = crate::parse(&input).unwrap();
+        if maze.there_and_back_again().is_some() {
+            return input;
+        }
+    }
+}
+
+fn valley(rng: &mut StdRng, size: usize) -> String {
+    let width = size.max(MIN_SIZE);
+    let height = (size / 2).max(2);
+    let wall = |gap: usize| {
+        (0..width + 2)
+            .map(|x| if x == gap { '.' } else { '#' })
+            .collect::<String>()
+            + "\n"
+    };
+    let mut input = wall(1);
+    for _ in 0..height {
+        input.push('#');
+        for x in 1..=width {
+            let blizzards: &[char] = match x == 1 || x == width {
+                true => &['<', '>'],
+                false => &['<', '>', '^', 'v'],
+            };
+            input.push(match rng.gen_bool(0.25) {
+                true => blizzards[rng.gen_range(0..blizzards.len())],
+                false => '.',
+            });
+        }
+        input += "#\n";
+    }
+    input + &wall(width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+    use aoc_common::gen::assert_generates;
+
+    #[test]
+    fn generate_test() {
+        for size in [0, 1, 2, 20] {
+            assert_generates::<Day24>(generate, size);
+        }
+    }
+}
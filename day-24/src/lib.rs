@@ -0,0 +1,336 @@
+pub mod gen;
+
+use aoc_common::{
+    animation::Render, fixture, search, Example, Grid, MaybeAnswer, ParseError, Solution, Source,
+};
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day24;
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const EXAMPLES: &'static [Example] = &[Example::new("test-input", fixture!("test-input"))
+        .part1("18")
+        .part2("54")];
+
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(maze: &Self::Input) -> impl Display {
+        let mut maze = maze.clone();
+        MaybeAnswer(maze.find_min_steps(maze.start, maze.target))
+    }
+
+    fn part2(maze: &Self::Input) -> impl Display {
+        let mut maze = maze.clone();
+        MaybeAnswer(maze.there_and_back_again())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Maze {
+    map: Grid<char>,
+    start: (isize, isize),
+    target: (isize, isize),
+    blizzards: Vec<((isize, isize), char)>,
+    /// Minutes walked so far.
+    minute: usize,
+    /// Positions taken by blizzards at every minute computed so far.
+    occupied: Vec<HashSet<(isize, isize)>>,
+}
+
+impl Maze {
+    /// Minutes it takes to walk from `start` to `target`, setting off where the previous walk
+    /// ended, `None` if the blizzards never let the expedition through.
+    fn find_min_steps(&mut self, start: (isize, isize), target: (isize, isize)) -> Option<usize> {
+        let steps = self.find_path(start, target)?.len() - 1;
+        self.minute += steps;
+        Some(steps)
+    }
+
+    /// Minutes it takes to reach the exit, go back for the snacks and reach the exit again.
+    fn there_and_back_again(&mut self) -> Option<usize> {
+        let there = self.find_min_steps(self.start, self.target)?;
+        let back = self.find_min_steps(self.target, self.start)?;
+        let there_again = self.find_min_steps(self.start, self.target)?;
+        Some(there + back + there_again)
+    }
+
+    /// Position at every minute of the quickest walk from `start` to `target`, both included,
+    /// `None` if the blizzards never let the expedition through.
+    fn find_path(
+        &mut self,
+        start: (isize, isize),
+        target: (isize, isize),
+    ) -> Option<Vec<(isize, isize)>> {
+        // the blizzards are back where they started every `period` minutes, so a walk only has
+        // to be tracked by the minute within that period
+        let period = self.period();
+        let path = search::bfs(
+            [(start, self.minute % period)],
+            |&(pos, phase)| {
+                let next = (phase + 1) % period;
+                self.advance_to(next);
+                [(1, 0), (0, 1), (0, -1), (-1, 0), (0, 0)]
+                    .into_iter()
+                    .map(|diff| (pos.0 + diff.0, pos.1 + diff.1))
+                    .filter(|next_pos| {
+                        self.map.get_signed(*next_pos).is_some_and(|&ch| ch != '#')
+                            && !self.occupied[next].contains(next_pos)
+                    })
+                    .map(|next_pos| (next_pos, next))
+                    .collect::<Vec<_>>()
+            },
+            |&(pos, _)| pos == target,
+        )?;
+        Some(path.nodes.into_iter().map(|(pos, _)| pos).collect())
+    }
+
+    /// Minutes after which every blizzard is back where it started.
+    fn period(&self) -> usize {
+        let (width, height) = (self.map.width().max(3) - 2, self.map.height().max(3) - 2);
+        let gcd = |mut a: usize, mut b: usize| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        width / gcd(width, height) * height
+    }
+
+    fn advance_to(&mut self, minute: usize) {
+        while self.occupied.len() <= minute {
+            if !self.occupied.is_empty() {
+                self.move_blizzards();
+            }
+            self.occupied
+                .push(self.blizzards.iter().map(|&(pos, _)| pos).collect());
+        }
+    }
+
+    fn move_blizzards(&mut self) {
+        self.blizzards = self
+            .blizzards
+            .clone()
+            .into_iter()
+            .map(|((y, x), ch)| {
+                let mut next_pos = match ch {
+                    '<' => (y, x - 1),
+                    '>' => (y, x + 1),
+                    'v' => (y + 1, x),
+                    '^' => (y - 1, x),
+                    _ => unreachable!(),
+                };
+                if self.map[(next_pos.0 as usize, next_pos.1 as usize)] == '#' {
+                    match ch {
+                        '<' | '>' => {
+                            if next_pos.1 == 0 {
+                                next_pos.1 = self.map.width() as isize - 2
+                            } else {
+                                next_pos.1 = 1
+                            }
+                        }
+                        'v' | '^' => {
+                            if next_pos.0 == 0 {
+                                next_pos.0 = self.map.height() as isize - 2
+                            } else {
+                                next_pos.0 = 1
+                            }
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                (next_pos, ch)
+            })
+            .collect();
+    }
+}
+
+/// The expedition walking to the exit along the path of part 1.
+struct Expedition {
+    maze: Maze,
+    path: Vec<(isize, isize)>,
+}
+impl Render for Expedition {
+    fn render(&self) -> String {
+        let mut map = self.maze.map.clone();
+        for &((y, x), ch) in &self.maze.blizzards {
+            let cell = &mut map[(y as usize, x as usize)];
+            *cell = match *cell {
+                '.' => ch,
+                '<' | '>' | 'v' | '^' => '2',
+                n => char::from_digit(n.to_digit(10).unwrap() + 1, 10).unwrap(),
+            };
+        }
+        let (y, x) = self.path[self.maze.minute];
+        map[(y as usize, x as usize)] = 'E';
+        map.to_string()
+    }
+
+    fn step(&mut self) -> bool {
+        if self.maze.minute + 1 == self.path.len() {
+            return false;
+        }
+        self.maze.move_blizzards();
+        self.maze.minute += 1;
+        true
+    }
+}
+
+pub fn animation(input: &str) -> Result<Box<dyn Render>, ParseError> {
+    let maze = parse(input)?;
+    let path = maze
+        .clone()
+        .find_path(maze.start, maze.target)
+        .ok_or_else(|| {
+            let last_line = input.lines().last().unwrap();
+            Source::new(input).error(last_line, "the exit cannot be reached from the entrance")
+        })?;
+    Ok(Box::new(Expedition { maze, path }))
+}
+
+fn parse(input: &str) -> Result<Maze, ParseError> {
+    let source = Source::new(input);
+    let mut map = Grid::parse(input, |ch| "#.<>^v".contains(ch).then_some(ch))?;
+    if map.height() < 2 {
+        return Err(source.eof("expected a valley surrounded by walls"));
+    }
+    let start = (
+        0,
+        map.row(0).iter().position(|&ch| ch == '.').ok_or_else(|| {
+            source.error(input.lines().next().unwrap(), "no entrance on the top row")
+        })? as isize,
+    );
+    // blizzards wrap around when they hit a wall, so they must not be able to reach anything else
+    let (height, width) = (map.height(), map.width());
+    let lines = input.lines().collect::<Vec<_>>();
+    for ((y, x), &ch) in map.iter() {
+        let cell = &lines[y][x..x + 1];
+        let blizzard = "<>^v".contains(ch);
+        if blizzard && (y == 0 || y == height - 1 || x == 0 || x == width - 1) {
+            return Err(source.error(cell, "blizzards must be inside the walls"));
+        }
+        if (x == 0 || x == width - 1) && ch != '#' {
+            return Err(source.error(cell, "expected a wall"));
+        }
+        if "^v".contains(ch) && (map[(0, x)] != '#' || map[(height - 1, x)] != '#') {
+            return Err(source.error(cell, "this blizzard would blow out of the valley"));
+        }
+    }
+    let blizzards = map
+        .iter()
+        .filter_map(|((y, x), &ch)| match ch {
+            '>' | '<' | 'v' | '^' => Some(((y as isize, x as isize), ch)),
+            _ => None,
+        })
+        .collect::<Vec<((isize, isize), char)>>();
+    blizzards.iter().for_each(|&(k, _)| {
+        map[(k.0 as usize, k.1 as usize)] = '.';
+    });
+    let last_y = map.height() - 1;
+    let target = (
+        last_y as isize,
+        map.row(last_y)
+            .iter()
+            .position(|&ch| ch == '.')
+            .ok_or_else(|| {
+                source.error(input.lines().last().unwrap(), "no exit on the bottom row")
+            })? as isize,
+    );
+    Ok(Maze {
+        map,
+        start,
+        target,
+        blizzards,
+        minute: 0,
+        occupied: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::example::assert_examples;
+
+    #[test]
+    fn part1_test() {
+        let mut maze = parse(fixture!("test-input")).unwrap();
+        assert_eq!(maze.find_min_steps(maze.start, maze.target), Some(18));
+    }
+
+    #[test]
+    fn part1_test_movement() {
+        let mut maze = parse(fixture!("test-input2")).unwrap();
+        maze.move_blizzards();
+        assert_eq!(maze.blizzards, [((2, 2), '>'), ((5, 4), 'v')]);
+        maze.move_blizzards();
+        assert_eq!(maze.blizzards, [((2, 3), '>'), ((1, 4), 'v')]);
+        maze.move_blizzards();
+        assert_eq!(maze.blizzards, [((2, 4), '>'), ((2, 4), 'v')]);
+        maze.move_blizzards();
+        assert_eq!(maze.blizzards, [((2, 5), '>'), ((3, 4), 'v')]);
+        maze.move_blizzards();
+        assert_eq!(maze.blizzards, [((2, 1), '>'), ((4, 4), 'v')]);
+    }
+
+    #[test]
+    fn part2_test() {
+        let mut maze = parse(fixture!("test-input")).unwrap();
+        assert_eq!(maze.find_min_steps(maze.start, maze.target), Some(18));
+        assert_eq!(maze.find_min_steps(maze.target, maze.start), Some(23));
+        assert_eq!(maze.find_min_steps(maze.start, maze.target), Some(13));
+    }
+
+    #[test]
+    fn render_test() {
+        let mut expedition = animation(fixture!("test-input")).unwrap();
+        assert_eq!(
+            expedition.render(),
+            "\
+#E######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#"
+        );
+        let mut minutes = 0;
+        while expedition.step() {
+            minutes += 1;
+        }
+        assert_eq!(minutes, 18);
+        assert!(expedition.render().ends_with("######E#"));
+    }
+
+    #[test]
+    fn unreachable_test() {
+        // the blizzard blows into the wall and wraps around onto itself, forever
+        let input = "#.#\n#<#\n#.#\n";
+        assert_eq!(Day24::solve(input).unwrap(), ["None", "None"]);
+        let err = animation(input).err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "#.#"));
+        assert_eq!(err.reason, "the exit cannot be reached from the entrance");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = parse("#.######\n#>>.<^<#\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, ">"));
+        assert_eq!(err.reason, "blizzards must be inside the walls");
+
+        let err = parse("#.###\n#^..#\n###.#\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "^"));
+        assert_eq!(err.reason, "this blizzard would blow out of the valley");
+
+        let err = parse("#.#\n#>.\n#.#\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.reason, "expected a wall");
+    }
+
+    #[test]
+    fn examples_test() {
+        assert_examples::<Day24>();
+    }
+}